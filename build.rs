@@ -0,0 +1,4 @@
+fn main() {
+    // New migration directories are not picked up by `embed_migrations!` on their own.
+    println!("cargo:rerun-if-changed=src/infrastructure/database/migrations");
+}
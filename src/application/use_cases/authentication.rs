@@ -1,40 +1,217 @@
+use std::{str::FromStr, sync::Arc};
 
-use std::{ sync::Arc};
-use anyhow::Result;
+use chrono::Utc;
+use tracing::warn;
+use uuid::Uuid;
 
-use crate::{domain::repositories::brawlers::BrawlerRepository, infrastructure::{self, jwt::{authentication_model::LoginModel, jwt_model::Passport}}};
+use crate::{
+    domain::{
+        errors::{DomainError, DomainResult},
+        repositories::{brawlers::BrawlerRepository, refresh_tokens::RefreshTokenRepository},
+        value_objects::roles::Role,
+    },
+    infrastructure::{
+        self,
+        jwt::{
+            authentication_model::{LoginModel, LogoutModel, RefreshTokenModel},
+            jwt_authority::JwtAuthority,
+            jwt_model::{Claims, Passport},
+            refresh_token,
+            revocation_list::RevocationList,
+        },
+    },
+};
 
-pub struct AuthenticationUseCase<T>
+pub struct AuthenticationUseCase<T1, T2>
 where
-    T: BrawlerRepository + Send + Sync {
-    brawler_repository: Arc<T>,
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+{
+    brawler_repository: Arc<T1>,
+    refresh_token_repository: Arc<T2>,
+    revocation_list: Arc<RevocationList>,
+    jwt_authority: Arc<JwtAuthority>,
 }
 
+impl<T1, T2> AuthenticationUseCase<T1, T2>
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+{
+    pub fn new(
+        brawler_repository: Arc<T1>,
+        refresh_token_repository: Arc<T2>,
+        revocation_list: Arc<RevocationList>,
+        jwt_authority: Arc<JwtAuthority>,
+    ) -> Self {
+        Self {
+            brawler_repository,
+            refresh_token_repository,
+            revocation_list,
+            jwt_authority,
+        }
+    }
+    pub async fn login(&self, login_model: LoginModel) -> DomainResult<Passport> {
+        let username = login_model.username.clone();
 
-impl <T> AuthenticationUseCase<T> 
-    where
-        T: BrawlerRepository + Send + Sync,
-    {
-        pub fn new(brawler_repository: Arc<T>) -> Self {
-            Self { brawler_repository }
+        let brawler_entity = self
+            .brawler_repository
+            .find_by_username(&username)
+            .await
+            .map_err(|e| match DomainError::from(e) {
+                DomainError::NotFound(_) => {
+                    DomainError::unauthorized("Invalid username or password!")
+                }
+                other => other,
+            })?;
+        let role = Role::from_str(&brawler_entity.role)?;
+        let hash_password = brawler_entity.password;
+        let login_password = login_model.password;
 
+        if !infrastructure::argon2::verify(login_password, hash_password)? {
+            return Err(DomainError::unauthorized("Invalid username or password!"));
         }
-        pub async fn login(&self, login_model: LoginModel) -> Result<Passport> {
-    let username = login_model.username.clone();
 
-    let brawler_entity = self.brawler_repository.find_by_username(&username).await?;
-    let hash_password = brawler_entity.password;
-    let login_password = login_model.password;
+        issue_passport(
+            self.refresh_token_repository.as_ref(),
+            &self.jwt_authority,
+            brawler_entity.id,
+            role,
+        )
+        .await
+    }
+
+    pub async fn refresh(&self, refresh_token_model: RefreshTokenModel) -> DomainResult<Passport> {
+        let token_hash = refresh_token::hash(&refresh_token_model.refresh_token);
 
-    if !infrastructure::argon2::verify(login_password, hash_password)? {
-        return Err(anyhow::anyhow!("Invalid password!"));
+        let stored_token = self
+            .refresh_token_repository
+            .find_by_hash(&token_hash)
+            .await
+            .map_err(|e| match DomainError::from(e) {
+                DomainError::NotFound(_) => DomainError::unauthorized("Invalid refresh token!"),
+                other => other,
+            })?;
+
+        if stored_token.revoked_at.is_some() {
+            return Err(DomainError::unauthorized("Refresh token has been revoked!"));
+        }
+        if stored_token.used_at.is_some() {
+            return Err(self
+                .reuse_detected(stored_token.family_id, stored_token.brawler_id)
+                .await);
+        }
+        if stored_token.expires_at <= Utc::now().naive_utc() {
+            return Err(DomainError::unauthorized("Refresh token has expired!"));
+        }
+
+        // The role is read again so that role changes apply from the next refresh on.
+        let brawler_entity = self
+            .brawler_repository
+            .find_by_id(stored_token.brawler_id)
+            .await?;
+        let role = Role::from_str(&brawler_entity.role)?;
+
+        let issued = refresh_token::issue(
+            stored_token.brawler_id,
+            stored_token.family_id,
+            self.jwt_authority.refresh_token_lifetime(),
+        );
+
+        let rotated = self
+            .refresh_token_repository
+            .rotate(stored_token.id, issued.entity)
+            .await?;
+        if !rotated {
+            // Another request used the same token between our read and the rotation.
+            return Err(self
+                .reuse_detected(stored_token.family_id, stored_token.brawler_id)
+                .await);
+        }
+
+        let passport = Passport::new(
+            stored_token.brawler_id,
+            role,
+            issued.token,
+            &self.jwt_authority,
+        )?;
+
+        Ok(passport)
     }
 
-    let passport = Passport::new(brawler_entity.id)?;
+    pub async fn logout(&self, claims: Claims, logout_model: LogoutModel) -> DomainResult<()> {
+        self.revocation_list.revoke(&claims).await?;
 
-    Ok(passport)
+        let Some(refresh_token) = logout_model.refresh_token else {
+            return Ok(());
+        };
+
+        let token_hash = refresh_token::hash(&refresh_token);
+        let stored_token = match self.refresh_token_repository.find_by_hash(&token_hash).await {
+            Ok(stored_token) => stored_token,
+            Err(e) => {
+                return match DomainError::from(e) {
+                    // Unknown refresh tokens are already unusable, logging out stays idempotent.
+                    DomainError::NotFound(_) => Ok(()),
+                    other => Err(other),
+                };
+            }
+        };
+
+        if stored_token.brawler_id.to_string() != claims.sub {
+            return Err(DomainError::forbidden(
+                "Refresh token belongs to another brawler!",
+            ));
+        }
+
+        self.refresh_token_repository
+            .revoke_family(stored_token.family_id)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn logout_all(&self, brawler_id: i32) -> DomainResult<()> {
+        self.revocation_list.revoke_all(brawler_id).await?;
+        self.refresh_token_repository.revoke_all(brawler_id).await?;
+
+        Ok(())
+    }
+
+    async fn reuse_detected(&self, family_id: Uuid, brawler_id: i32) -> DomainError {
+        warn!(
+            "Refresh token reuse detected for brawler({}), revoking family {}",
+            brawler_id, family_id
+        );
+
+        match self.refresh_token_repository.revoke_family(family_id).await {
+            Ok(_) => {
+                DomainError::unauthorized("Refresh token reuse detected, please log in again!")
+            }
+            Err(e) => DomainError::from(e),
+        }
+    }
 }
 
+/// Issues a fresh passport whose refresh token starts a new token family.
+pub async fn issue_passport<T>(
+    refresh_token_repository: &T,
+    jwt_authority: &JwtAuthority,
+    brawler_id: i32,
+    role: Role,
+) -> DomainResult<Passport>
+where
+    T: RefreshTokenRepository + Send + Sync,
+{
+    let issued = refresh_token::issue(
+        brawler_id,
+        Uuid::new_v4(),
+        jwt_authority.refresh_token_lifetime(),
+    );
+
+    refresh_token_repository.add(issued.entity).await?;
 
-    
-    }
\ No newline at end of file
+    let passport = Passport::new(brawler_id, role, issued.token, jwt_authority)?;
+
+    Ok(passport)
+}
\ No newline at end of file
@@ -1,66 +1,370 @@
 use crate::{
+    application::use_cases::authentication::issue_passport,
     domain::{
-        repositories::brawlers::BrawlerRepository,
+        errors::{DomainError, DomainResult},
+        repositories::{
+            brawlers::BrawlerRepository, image_storage::ImageStorage,
+            refresh_tokens::RefreshTokenRepository,
+        },
         value_objects::{
-            base64_image::Base64Image, brawler_model::RegisterBrawlerModel,
-            uploaded_image::UploadedImage,
+            base64_image::Base64Image,
+            brawler_model::{
+                BrawlerModel, BrawlerProfileModel, ChangePasswordModel, EditBrawlerModel,
+                RegisterBrawlerModel,
+            },
+            leaderboard_filter::{
+                DEFAULT_LEADERBOARD_PAGE_SIZE, LeaderboardFilter, LeaderboardModel,
+                MAX_LEADERBOARD_PAGE_SIZE,
+            },
+            roles::Role,
+            uploaded_image::{AvatarSize, UploadImageOptions, UploadedAvatar, UploadedImage},
         },
     },
     infrastructure::{
-        argon2::hash,
-        cloudinary::{ UploadImageOptions},
-        jwt::jwt_model::Passport,
+        argon2::{hash, verify},
+        image_processing,
+        image_storage::cleanup_queue::ImageCleanupQueue,
+        jwt::{jwt_authority::JwtAuthority, jwt_model::Passport, revocation_list::RevocationList},
     },
 };
-use anyhow::Result;
-use std::sync::Arc;
+use std::{str::FromStr, sync::Arc};
+use uuid::Uuid;
+
+/// Square avatar sizes in pixels, smallest first.
+pub const AVATAR_SIZES: [u32; 3] = [64, 128, 256];
 
-pub struct BrawlersUseCase<T>
+pub struct BrawlersUseCase<T1, T2>
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
 {
-    brawler_repository: Arc<T>,
+    brawler_repository: Arc<T1>,
+    refresh_token_repository: Arc<T2>,
+    image_storage: Arc<dyn ImageStorage + Send + Sync>,
+    image_cleanup: Arc<ImageCleanupQueue>,
+    revocation_list: Arc<RevocationList>,
+    jwt_authority: Arc<JwtAuthority>,
+    max_avatar_bytes: usize,
 }
 
-impl<T> BrawlersUseCase<T>
+impl<T1, T2> BrawlersUseCase<T1, T2>
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
 {
-    pub fn new(brawler_repository: Arc<T>) -> Self {
-        Self { brawler_repository }
+    pub fn new(
+        brawler_repository: Arc<T1>,
+        refresh_token_repository: Arc<T2>,
+        image_storage: Arc<dyn ImageStorage + Send + Sync>,
+        image_cleanup: Arc<ImageCleanupQueue>,
+        revocation_list: Arc<RevocationList>,
+        jwt_authority: Arc<JwtAuthority>,
+        max_avatar_bytes: usize,
+    ) -> Self {
+        Self {
+            brawler_repository,
+            refresh_token_repository,
+            image_storage,
+            image_cleanup,
+            revocation_list,
+            jwt_authority,
+            max_avatar_bytes,
+        }
     }
 
-    pub async fn register(&self, mut register_model: RegisterBrawlerModel) -> Result<Passport> {
+    pub async fn register(&self, mut register_model: RegisterBrawlerModel) -> DomainResult<Passport> {
+        if register_model.username.trim().is_empty() {
+            return Err(DomainError::validation("Username cannot be empty!"));
+        }
+        if register_model.password.is_empty() {
+            return Err(DomainError::validation("Password cannot be empty!"));
+        }
+
         let hashed_password = hash(register_model.password.clone())?;
 
         register_model.password = hashed_password;
 
         let register_entity = register_model.to_entity();
 
-        let brawler_id = self.brawler_repository.register(register_entity).await?;
+        let brawler_id = self
+            .brawler_repository
+            .register(register_entity)
+            .await
+            .map_err(|e| match DomainError::from(e) {
+                DomainError::Conflict(_) => DomainError::conflict("Username is already taken!"),
+                other => other,
+            })?;
 
-        let passport = Passport::new(brawler_id)?;
-        Ok(passport)
+        issue_passport(
+            self.refresh_token_repository.as_ref(),
+            &self.jwt_authority,
+            brawler_id,
+            Role::Brawler,
+        )
+        .await
     }
     pub async fn upload_avatar(
         &self,
         base64_image: String,
         brawler_id: i32,
-    ) -> Result<UploadedImage> {
-        let option = UploadImageOptions {
-            folder: Some("brawlers_avatar".to_string()),
-            public_id: Some(brawler_id.to_string()),
-            transformation: Some("c_scale,w_256".to_string()),
+    ) -> DomainResult<UploadedAvatar> {
+        let base64_image = Base64Image::new(base64_image)
+            .map_err(|e| DomainError::validation(e.to_string()))?;
+        let bytes = base64_image.decode()?;
+        self.ensure_avatar_size(bytes.len())?;
+
+        self.store_avatar(bytes, brawler_id).await
+    }
+
+    /// The multipart variant of [`Self::upload_avatar`], `bytes` is the raw file.
+    pub async fn upload_avatar_file(
+        &self,
+        bytes: Vec<u8>,
+        brawler_id: i32,
+    ) -> DomainResult<UploadedAvatar> {
+        self.ensure_avatar_size(bytes.len())?;
+        Base64Image::sniff_mime_type(&bytes).map_err(|e| DomainError::validation(e.to_string()))?;
+
+        self.store_avatar(bytes, brawler_id).await
+    }
+
+    pub fn ensure_avatar_size(&self, size: usize) -> DomainResult<()> {
+        if size > self.max_avatar_bytes {
+            return Err(DomainError::payload_too_large(format!(
+                "Avatar cannot be larger than {} KiB!",
+                self.max_avatar_bytes / 1024
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Stores every size of [`AVATAR_SIZES`], points the brawler at them with
+    /// the largest as `avatar_url` and queues the avatar they replace for deletion.
+    async fn store_avatar(&self, bytes: Vec<u8>, brawler_id: i32) -> DomainResult<UploadedAvatar> {
+        let previous_public_id = self
+            .brawler_repository
+            .find_by_id(brawler_id)
+            .await?
+            .avatar_public_id;
+
+        let square_images =
+            tokio::task::spawn_blocking(move || image_processing::square_pngs(&bytes, &AVATAR_SIZES))
+                .await
+                .map_err(anyhow::Error::from)?
+                .map_err(|e| DomainError::validation(format!("Invalid image: {}", e)))?;
+
+        // A fresh version per upload, so caches never serve the replaced avatar.
+        let version = &Uuid::new_v4().simple().to_string()[..8];
+        let mut sizes = Vec::with_capacity(square_images.len());
+        for square_image in square_images {
+            let option = UploadImageOptions {
+                folder: Some("brawlers_avatar".to_string()),
+                public_id: Some(format!("{}_{}_{}", brawler_id, version, square_image.size)),
+                transformation: None,
+            };
+
+            let uploaded_image = match Base64Image::from_bytes(&square_image.png) {
+                Ok(base64_image) => self.image_storage.upload(base64_image, option).await,
+                Err(e) => Err(e),
+            };
+            match uploaded_image {
+                Ok(uploaded_image) => sizes.push(AvatarSize {
+                    size: square_image.size,
+                    url: uploaded_image.url,
+                    public_id: uploaded_image.public_id,
+                }),
+                Err(e) => {
+                    self.discard_sizes(&sizes);
+                    return Err(e.into());
+                }
+            }
+        }
+
+        let largest = sizes
+            .last()
+            .ok_or_else(|| anyhow::anyhow!("No avatar sizes configured"))?;
+        let uploaded_avatar = UploadedAvatar {
+            url: largest.url.clone(),
+            public_id: largest.public_id.clone(),
+            sizes,
         };
 
-        let base64_image = Base64Image::new(base64_image)?;
+        if let Err(e) = self
+            .brawler_repository
+            .update_avatar(
+                brawler_id,
+                UploadedImage::new(uploaded_avatar.url.clone(), uploaded_avatar.public_id.clone()),
+                uploaded_avatar.avatar_sizes(),
+            )
+            .await
+        {
+            self.discard_sizes(&uploaded_avatar.sizes);
+            return Err(e.into());
+        }
+
+        if let Some(previous_public_id) = previous_public_id {
+            self.image_cleanup
+                .enqueue(avatar_public_ids(&previous_public_id));
+        }
+
+        Ok(uploaded_avatar)
+    }
+
+    /// Clears the avatar and queues its images for deletion. Brawlers without
+    /// one are left as they are.
+    pub async fn remove_avatar(&self, brawler_id: i32) -> DomainResult<()> {
+        let brawler_entity = self.brawler_repository.find_by_id(brawler_id).await?;
+        let Some(public_id) = brawler_entity.avatar_public_id else {
+            return Ok(());
+        };
+
+        self.brawler_repository.remove_avatar(brawler_id).await?;
+        self.image_cleanup.enqueue(avatar_public_ids(&public_id));
+
+        Ok(())
+    }
+
+    fn discard_sizes(&self, sizes: &[AvatarSize]) {
+        self.image_cleanup
+            .enqueue(sizes.iter().map(|size| size.public_id.clone()).collect());
+    }
+
+    pub async fn get_me(&self, brawler_id: i32) -> DomainResult<BrawlerProfileModel> {
+        let brawler_entity = self.brawler_repository.find_by_id(brawler_id).await?;
+
+        Ok(brawler_entity.to_profile_model())
+    }
+
+    pub async fn get_profile(&self, brawler_id: i32) -> DomainResult<BrawlerModel> {
+        let brawler_model = self.brawler_repository.find_profile(brawler_id).await?;
+
+        Ok(brawler_model)
+    }
+
+    pub async fn leaderboard(&self, filter: &LeaderboardFilter) -> DomainResult<LeaderboardModel> {
+        let sort_by = filter.sort_by.unwrap_or_default();
+        let page = filter.page.unwrap_or(1);
+        let page_size = filter.page_size.unwrap_or(DEFAULT_LEADERBOARD_PAGE_SIZE);
+
+        if page < 1 {
+            return Err(DomainError::validation("Page must be at least 1!"));
+        }
+        if !(1..=MAX_LEADERBOARD_PAGE_SIZE).contains(&page_size) {
+            return Err(DomainError::validation(format!(
+                "Page size must be between 1 and {}!",
+                MAX_LEADERBOARD_PAGE_SIZE
+            )));
+        }
 
-        let uploaded_image = self
+        let Some(offset) = (page - 1).checked_mul(page_size) else {
+            return Err(DomainError::validation("Page is out of range!"));
+        };
+
+        let brawlers = self
             .brawler_repository
-            .upload_avatar(brawler_id, base64_image, option)
+            .leaderboard(sort_by, page_size, offset)
+            .await?;
+
+        Ok(LeaderboardModel {
+            sort_by,
+            page,
+            page_size,
+            brawlers,
+        })
+    }
+
+    pub async fn edit_me(
+        &self,
+        brawler_id: i32,
+        mut edit_brawler_model: EditBrawlerModel,
+    ) -> DomainResult<BrawlerProfileModel> {
+        let Some(display_name) = edit_brawler_model.display_name else {
+            return Err(DomainError::validation("Nothing to update!"));
+        };
+
+        let display_name = display_name.trim();
+        if display_name.is_empty() {
+            return Err(DomainError::validation("Display name cannot be empty!"));
+        }
+        if display_name.chars().count() > 50 {
+            return Err(DomainError::validation(
+                "Display name must be at most 50 characters long!",
+            ));
+        }
+        edit_brawler_model.display_name = Some(display_name.to_string());
+
+        self.brawler_repository
+            .edit(brawler_id, edit_brawler_model.to_entity())
+            .await?;
+
+        self.get_me(brawler_id).await
+    }
+
+    /// Signs out every session, access tokens included. The caller continues
+    /// with the returned passport, issued after the cutoff.
+    pub async fn change_password(
+        &self,
+        brawler_id: i32,
+        change_password_model: ChangePasswordModel,
+    ) -> DomainResult<Passport> {
+        if change_password_model.new_password.is_empty() {
+            return Err(DomainError::validation("Password cannot be empty!"));
+        }
+
+        let brawler_entity = self.brawler_repository.find_by_id(brawler_id).await?;
+
+        if !verify(change_password_model.current_password, brawler_entity.password)? {
+            return Err(DomainError::forbidden("Current password is incorrect!"));
+        }
+
+        let hashed_password = hash(change_password_model.new_password)?;
+        self.brawler_repository
+            .update_password(brawler_id, hashed_password)
             .await?;
 
-        Ok(uploaded_image)
+        self.revocation_list.revoke_all(brawler_id).await?;
+        self.refresh_token_repository.revoke_all(brawler_id).await?;
+
+        issue_passport(
+            self.refresh_token_repository.as_ref(),
+            &self.jwt_authority,
+            brawler_id,
+            Role::from_str(&brawler_entity.role)?,
+        )
+        .await
     }
 
+    /// Tokens already issued keep the old role until they are refreshed.
+    pub async fn change_role(&self, brawler_id: i32, role: Role) -> DomainResult<()> {
+        self.brawler_repository
+            .update_role(brawler_id, role)
+            .await?;
+
+        Ok(())
+    }
+
+}
+
+/// Every stored size of the avatar whose largest size is `public_id`. Sizes
+/// only differ in their `_{size}` suffix, avatars stored before sizes were
+/// introduced are a single image.
+fn avatar_public_ids(public_id: &str) -> Vec<String> {
+    let largest_suffix = format!("_{}", AVATAR_SIZES[AVATAR_SIZES.len() - 1]);
+    let (stem, extension) = match public_id.rsplit_once('.') {
+        Some((stem, extension)) if !extension.contains('/') => (stem, Some(extension)),
+        _ => (public_id, None),
+    };
+
+    let Some(prefix) = stem.strip_suffix(&largest_suffix) else {
+        return vec![public_id.to_string()];
+    };
+
+    AVATAR_SIZES
+        .iter()
+        .map(|size| match extension {
+            Some(extension) => format!("{}_{}.{}", prefix, size, extension),
+            None => format!("{}_{}", prefix, size),
+        })
+        .collect()
 }
\ No newline at end of file
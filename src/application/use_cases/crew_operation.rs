@@ -1,87 +1,104 @@
 use crate::domain::{
     entities::crew_memberships::CrewMembershipEntity,
-    repositories::{
-        crew_operation::CrewOperationRepository, mission_viewing::MissionViewingRepository,
-    },
-    value_objects::mission_statuses::MissionStatuses,
+    errors::{DomainError, DomainResult},
+    repositories::transaction_provider::{MissionTransaction, TransactionProvider},
 };
-use anyhow::Result;
 use std::sync::Arc;
 
-pub struct CrewOperationUseCase<T1, T2>
+pub struct CrewOperationUseCase<T>
 where
-    T1: CrewOperationRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
+    T: TransactionProvider + Send + Sync,
 {
-    crew_operation_repository: Arc<T1>,
-    mission_viewing_repository: Arc<T2>,
+    transaction_provider: Arc<T>,
 }
 
-impl<T1, T2> CrewOperationUseCase<T1, T2>
+impl<T> CrewOperationUseCase<T>
 where
-    T1: CrewOperationRepository + Send + Sync + 'static,
-    T2: MissionViewingRepository + Send + Sync,
+    T: TransactionProvider + Send + Sync,
 {
-    pub fn new(crew_operation_repository: Arc<T1>, mission_viewing_repository: Arc<T2>) -> Self {
+    pub fn new(transaction_provider: Arc<T>) -> Self {
         Self {
-            crew_operation_repository,
-            mission_viewing_repository,
+            transaction_provider,
         }
     }
 
-    pub async fn join(&self, mission_id: i32, brawler_id: i32) -> Result<()> {
-        let max_crew_per_mission = std::env::var("MAX_CREW_PER_MISSION")
-            .expect("missing value")
-            .parse()?;
+    /// The mission row stays locked until the membership is written, so two
+    /// brawlers racing for the last slot cannot both get in.
+    pub async fn join(&self, mission_id: i32, brawler_id: i32) -> DomainResult<()> {
+        self.transaction_provider
+            .transaction(move |transaction| {
+                let mission = transaction.lock_mission(mission_id)?;
 
-        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+                if mission.chief_id == brawler_id {
+                    return Err(DomainError::conflict(
+                        "Chiefs cannot join their own missions as crew members",
+                    ));
+                }
 
-        if mission.chief_id == brawler_id {
-            return Err(anyhow::anyhow!(
-                "Chiefs cannot join their own missions as crew members"
-            ));
-        }
-
-        let crew_count = self
-            .mission_viewing_repository
-            .crew_counting(mission_id)
-            .await?;
+                if !mission.status.is_joinable() {
+                    return Err(DomainError::conflict("Mission is not joinable"));
+                }
 
-        let mission_status_condition = mission.status == MissionStatuses::Open.to_string()
-            || mission.status == MissionStatuses::Failed.to_string();
-        if !mission_status_condition {
-            return Err(anyhow::anyhow!("Mission is not joinable"));
-        }
-        let crew_count_condition = crew_count < max_crew_per_mission;
-        if !crew_count_condition {
-            return Err(anyhow::anyhow!("Mission is full"));
-        }
+                let crew_count = transaction.crew_count(mission_id)?;
+                let crew_count_condition = crew_count < i64::from(mission.max_crew);
+                if !crew_count_condition {
+                    return Err(DomainError::conflict("Mission is full"));
+                }
 
-        self.crew_operation_repository
-            .join(CrewMembershipEntity {
-                mission_id,
-                brawler_id,
+                transaction
+                    .join(CrewMembershipEntity {
+                        mission_id,
+                        brawler_id,
+                    })
+                    .map_err(|e| match DomainError::from(e) {
+                        DomainError::Conflict(_) => {
+                            DomainError::conflict("Brawler has already joined this mission")
+                        }
+                        other => other,
+                    })
             })
-            .await?;
-
-        Ok(())
+            .await
     }
 
-    pub async fn leave(&self, mission_id: i32, brawler_id: i32) -> Result<()> {
-        let mission = self.mission_viewing_repository.get_one(mission_id).await?;
+    pub async fn leave(&self, mission_id: i32, brawler_id: i32) -> DomainResult<()> {
+        self.transaction_provider
+            .transaction(move |transaction| {
+                let mission = transaction.lock_mission(mission_id)?;
 
-        let leaving_condition = mission.status == MissionStatuses::Open.to_string()
-            || mission.status == MissionStatuses::Failed.to_string();
-        if !leaving_condition {
-            return Err(anyhow::anyhow!("Mission is not leavable"));
-        }
-        self.crew_operation_repository
-            .leave(CrewMembershipEntity {
-                mission_id,
-                brawler_id,
+                if !mission.status.is_joinable() {
+                    return Err(DomainError::conflict("Mission is not leavable"));
+                }
+
+                remove_crew_member(transaction, mission_id, brawler_id)
             })
-            .await?;
+            .await
+    }
+
+    /// Removes a crew member on behalf of an admin, whatever the mission status.
+    pub async fn remove_member(&self, mission_id: i32, brawler_id: i32) -> DomainResult<()> {
+        self.transaction_provider
+            .transaction(move |transaction| {
+                transaction.lock_mission(mission_id)?;
 
-        Ok(())
+                remove_crew_member(transaction, mission_id, brawler_id)
+            })
+            .await
     }
+}
+
+fn remove_crew_member<T>(transaction: &mut T, mission_id: i32, brawler_id: i32) -> DomainResult<()>
+where
+    T: MissionTransaction,
+{
+    transaction
+        .leave(CrewMembershipEntity {
+            mission_id,
+            brawler_id,
+        })
+        .map_err(|e| match DomainError::from(e) {
+            DomainError::NotFound(_) => {
+                DomainError::not_found("Brawler is not a crew member of this mission")
+            }
+            other => other,
+        })
 }
\ No newline at end of file
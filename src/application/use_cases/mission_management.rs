@@ -1,44 +1,69 @@
 use std::sync::Arc;
 
 use crate::{domain::{
+    errors::{DomainError, DomainResult},
     repositories::{
-        mission_management::MissionManagementRepository, mission_viewing::MissionViewingRepository,
+        mission_management::MissionManagementRepository,
+        transaction_provider::{MissionTransaction, TransactionProvider},
+    },
+    value_objects::{
+        mission_model::{AddMissionModel, EditMissionModel},
+        mission_statuses::MissionStatuses,
     },
-    value_objects::mission_model::{AddMissionModel, EditMissionModel},
 }};
 
 pub struct MissionManagementUseCase<T1, T2>
 where
     T1: MissionManagementRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
+    T2: TransactionProvider + Send + Sync,
 {
     mission_management_repository: Arc<T1>,
-    mission_viewing_repository: Arc<T2>,
+    transaction_provider: Arc<T2>,
+    max_crew_per_mission: u32,
 }
 
-use anyhow::Result;
 impl<T1, T2> MissionManagementUseCase<T1, T2>
 where
     T1: MissionManagementRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
+    T2: TransactionProvider + Send + Sync,
 {
     pub fn new(
         mission_management_repository: Arc<T1>,
-        mission_viewing_repository: Arc<T2>,
+        transaction_provider: Arc<T2>,
+        max_crew_per_mission: u32,
     ) -> Self {
         Self {
             mission_management_repository,
-            mission_viewing_repository,
+            transaction_provider,
+            max_crew_per_mission,
         }
     }
 
-    pub async fn add(&self, chief_id: i32, add_mission_model: AddMissionModel) -> Result<i32> {
-        
+    pub async fn add(&self, chief_id: i32, add_mission_model: AddMissionModel) -> DomainResult<i32> {
+
         if add_mission_model.name.trim().is_empty() || add_mission_model.name.trim().len()<3 {
-            return Err(anyhow::anyhow!("Mission name must be at least 3 characters long!"));
+            return Err(DomainError::validation("Mission name must be at least 3 characters long!"));
+        }
+
+        let max_crew_limit = i32::try_from(self.max_crew_per_mission).unwrap_or(i32::MAX);
+        let min_crew = add_mission_model.min_crew.unwrap_or(1);
+        let max_crew = add_mission_model.max_crew.unwrap_or(max_crew_limit);
+        if min_crew < 1 {
+            return Err(DomainError::validation("Minimum crew must be at least 1!"));
+        }
+        if max_crew < min_crew {
+            return Err(DomainError::validation(
+                "Maximum crew cannot be lower than the minimum crew!",
+            ));
+        }
+        if max_crew > max_crew_limit {
+            return Err(DomainError::validation(format!(
+                "Maximum crew cannot exceed {}!",
+                max_crew_limit
+            )));
         }
 
-        let insert_mission_entity = add_mission_model.to_entity(chief_id);
+        let insert_mission_entity = add_mission_model.to_entity(chief_id, min_crew, max_crew);
 
         let result = self
             .mission_management_repository
@@ -53,52 +78,78 @@ where
         mission_id: i32,
         chief_id: i32,
         mut edit_mission_model: EditMissionModel,
-    ) -> Result<i32> {
+    ) -> DomainResult<i32> {
 
         if let Some(name) = edit_mission_model.name {
             if name.trim().is_empty() {
                 edit_mission_model.name = None;
             }else if name.trim().len()<3 {
-                return Err(anyhow::anyhow!("Mission name must be at least 3 characters long!"));
+                return Err(DomainError::validation("Mission name must be at least 3 characters long!"));
             }else{
                 edit_mission_model.name = Some(name.trim().to_string());
             }
         }
 
-        let crew_count = self
-            .mission_viewing_repository
-            .crew_counting(mission_id)
-            .await?;
-        if crew_count > 0 {
-            return Err(anyhow::anyhow!(
-                "Mission has been taken by brawler for now!"
-            ));
-        }
-
         let edit_mission_entity = edit_mission_model.to_entity(chief_id);
 
+        self.transaction_provider
+            .transaction(move |transaction| {
+                ensure_editable(transaction, mission_id, chief_id)?;
+
+                Ok(transaction.edit_mission(mission_id, edit_mission_entity)?)
+            })
+            .await
+    }
+
+    pub async fn remove(&self, mission_id: i32, chief_id: i32) -> DomainResult<()> {
+        self.transaction_provider
+            .transaction(move |transaction| {
+                ensure_editable(transaction, mission_id, chief_id)?;
+
+                Ok(transaction.remove_mission(mission_id, chief_id)?)
+            })
+            .await
+    }
+
+    /// Brings back a removed mission on behalf of an admin.
+    pub async fn restore(&self, mission_id: i32) -> DomainResult<i32> {
         let result = self
             .mission_management_repository
-            .edit(mission_id, edit_mission_entity)
-            .await?;
+            .restore(mission_id)
+            .await
+            .map_err(|e| match DomainError::from(e) {
+                DomainError::NotFound(_) => DomainError::not_found("No removed mission to restore!"),
+                other => other,
+            })?;
 
         Ok(result)
     }
 
-    pub async fn remove(&self, mission_id: i32, chief_id: i32) -> Result<()> {
-        let crew_count = self
-            .mission_viewing_repository
-            .crew_counting(mission_id)
-            .await?;
-        if crew_count > 0 {
-            return Err(anyhow::anyhow!(
-                "Mission has been taken by brawler for now!"
-            ));
-        }
+}
 
-        self.mission_management_repository
-            .remove(mission_id, chief_id)
-            .await?;
-        Ok(())
+/// Locks the mission first, so nobody can join between the check and the
+/// change that follows it in the same transaction.
+fn ensure_editable<T>(transaction: &mut T, mission_id: i32, chief_id: i32) -> DomainResult<()>
+where
+    T: MissionTransaction,
+{
+    let mission = transaction.lock_mission(mission_id)?;
+
+    if mission.chief_id != chief_id {
+        return Err(DomainError::forbidden(
+            "Only the mission chief can manage this mission!",
+        ));
+    }
+    if mission.status != MissionStatuses::Open {
+        return Err(DomainError::conflict("Only open missions can be changed!"));
     }
+
+    let crew_count = transaction.crew_count(mission_id)?;
+    if crew_count > 0 {
+        return Err(DomainError::conflict(
+            "Mission has been taken by brawler for now!"
+        ));
+    }
+
+    Ok(())
 }
\ No newline at end of file
@@ -1,91 +1,209 @@
 use std::sync::Arc;
 
-use anyhow::Result;
-
 use crate::domain::{
-    repositories::{
-        mission_operation::MissionOperationRepository, mission_viewing::MissionViewingRepository,
-    },
+    entities::{mission_status_history::AddMissionStatusHistoryEntity, missions::MissionEntity},
+    errors::{DomainError, DomainResult},
+    repositories::transaction_provider::{MissionTransaction, TransactionProvider},
     value_objects::mission_statuses::MissionStatuses,
 };
-pub struct MissionOperationUseCase<T1, T2>
+
+const MAX_REASON_LENGTH: usize = 500;
+
+/// Every stage change locks the mission row first, so it cannot interleave
+/// with joins, leaves or another stage change of the same mission.
+pub struct MissionOperationUseCase<T>
 where
-    T1: MissionOperationRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
+    T: TransactionProvider + Send + Sync,
 {
-    mission_operation_repository: Arc<T1>,
-    missiom_viewing_repository: Arc<T2>,
+    transaction_provider: Arc<T>,
 }
 
-impl<T1, T2> MissionOperationUseCase<T1, T2>
+impl<T> MissionOperationUseCase<T>
 where
-    T1: MissionOperationRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
+    T: TransactionProvider + Send + Sync,
 {
-    pub fn new(mission_operation_repository: Arc<T1>, missiom_viewing_repository: Arc<T2>) -> Self {
+    pub fn new(transaction_provider: Arc<T>) -> Self {
         Self {
-            mission_operation_repository,
-            missiom_viewing_repository,
+            transaction_provider,
         }
     }
 
-    pub async fn in_progress(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
-        let mission = self.missiom_viewing_repository.get_one(mission_id).await?;
+    pub async fn in_progress(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        reason: Option<String>,
+    ) -> DomainResult<i32> {
+        self.change_status(
+            mission_id,
+            chief_id,
+            Some(chief_id),
+            MissionStatuses::InProgress,
+            reason,
+        )
+        .await
+    }
 
-        let crew_count = self
-            .missiom_viewing_repository
-            .crew_counting(mission_id)
-            .await?;
+    pub async fn to_completed(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        reason: Option<String>,
+    ) -> DomainResult<i32> {
+        self.change_status(
+            mission_id,
+            chief_id,
+            Some(chief_id),
+            MissionStatuses::Completed,
+            reason,
+        )
+        .await
+    }
 
-        let is_status_open_or_fail = mission.status == MissionStatuses::Open.to_string()
-            || mission.status == MissionStatuses::Failed.to_string();
+    pub async fn to_failed(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        reason: Option<String>,
+    ) -> DomainResult<i32> {
+        self.change_status(
+            mission_id,
+            chief_id,
+            Some(chief_id),
+            MissionStatuses::Failed,
+            reason,
+        )
+        .await
+    }
 
-        let max_crew_per_mission = std::env::var("MAX_CREW_PER_MISSION")
-            .expect("missing value")
-            .parse()?;
+    pub async fn to_cancelled(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        reason: Option<String>,
+    ) -> DomainResult<i32> {
+        self.change_status(
+            mission_id,
+            chief_id,
+            Some(chief_id),
+            MissionStatuses::Cancelled,
+            reason,
+        )
+        .await
+    }
 
-        let update_condition = is_status_open_or_fail
-            && crew_count > 0
-            && crew_count < max_crew_per_mission
-            && mission.chief_id == chief_id;
-        if !update_condition {
-            return Err(anyhow::anyhow!("Invalid condition to change stages!"));
-        }
+    /// Opens a failed mission again so that crew can join before the next attempt.
+    pub async fn re_open(
+        &self,
+        mission_id: i32,
+        chief_id: i32,
+        reason: Option<String>,
+    ) -> DomainResult<i32> {
+        self.change_status(
+            mission_id,
+            chief_id,
+            Some(chief_id),
+            MissionStatuses::Open,
+            reason,
+        )
+        .await
+    }
 
-        let result = self
-            .mission_operation_repository
-            .to_progress(mission_id, chief_id)
-            .await?;
-        Ok(result)
+    /// Fails an open or running mission on behalf of an admin, whoever its chief is.
+    pub async fn force_close(
+        &self,
+        mission_id: i32,
+        admin_id: i32,
+        reason: Option<String>,
+    ) -> DomainResult<i32> {
+        self.change_status(mission_id, admin_id, None, MissionStatuses::Failed, reason)
+            .await
     }
-    pub async fn to_completed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
-        let mission = self.missiom_viewing_repository.get_one(mission_id).await?;
 
-        let update_condition = mission.status == MissionStatuses::InProgress.to_string()
-            && mission.chief_id == chief_id;
-        if !update_condition {
-            return Err(anyhow::anyhow!("Invalid condition to change stages!"));
-        }
-        let result = self
-            .mission_operation_repository
-            .to_completed(mission_id, chief_id)
-            .await?;
+    /// Without a `chief_id` the change is made by an admin, regardless of who
+    /// leads the mission and with the admin transitions allowed.
+    async fn change_status(
+        &self,
+        mission_id: i32,
+        actor_id: i32,
+        chief_id: Option<i32>,
+        to_status: MissionStatuses,
+        reason: Option<String>,
+    ) -> DomainResult<i32> {
+        let reason = normalize_reason(reason)?;
+
+        self.transaction_provider
+            .transaction(move |transaction| {
+                let (mission, to_status) = match chief_id {
+                    Some(chief_id) => {
+                        let mission = chiefs_mission(transaction, mission_id, chief_id)?;
+                        let to_status = mission.status.transition_to(to_status)?;
+                        (mission, to_status)
+                    }
+                    None => {
+                        let mission = transaction.lock_mission(mission_id)?;
+                        let to_status = mission.status.admin_transition_to(to_status)?;
+                        (mission, to_status)
+                    }
+                };
+                let from_status = mission.status;
+
+                if to_status == MissionStatuses::InProgress {
+                    let crew_count = transaction.crew_count(mission_id)?;
+                    let update_condition = (i64::from(mission.min_crew)
+                        ..=i64::from(mission.max_crew))
+                        .contains(&crew_count);
+                    if !update_condition {
+                        return Err(DomainError::conflict(
+                            "Invalid crew size to start the mission!",
+                        ));
+                    }
+                }
 
-        Ok(result)
+                transaction.change_status(AddMissionStatusHistoryEntity {
+                    mission_id,
+                    from_status,
+                    to_status,
+                    actor_id,
+                    reason,
+                })?;
+
+                Ok(mission_id)
+            })
+            .await
     }
-    pub async fn to_failed(&self, mission_id: i32, chief_id: i32) -> Result<i32> {
-        let mission = self.missiom_viewing_repository.get_one(mission_id).await?;
+}
 
-        let update_condition = mission.status == MissionStatuses::InProgress.to_string()
-            && mission.chief_id == chief_id;
-        if !update_condition {
-            return Err(anyhow::anyhow!("Invalid condition to change stages!"));
-        }
-        let result = self
-            .mission_operation_repository
-            .to_failed(mission_id, chief_id)
-            .await?;
+fn chiefs_mission<T>(transaction: &mut T, mission_id: i32, chief_id: i32) -> DomainResult<MissionEntity>
+where
+    T: MissionTransaction,
+{
+    let mission = transaction.lock_mission(mission_id)?;
+
+    if mission.chief_id != chief_id {
+        return Err(DomainError::forbidden(
+            "Only the mission chief can change its stage!",
+        ));
+    }
+
+    Ok(mission)
+}
 
-        Ok(result)
+fn normalize_reason(reason: Option<String>) -> DomainResult<Option<String>> {
+    let Some(reason) = reason else {
+        return Ok(None);
+    };
+
+    let reason = reason.trim();
+    if reason.is_empty() {
+        return Ok(None);
+    }
+    if reason.chars().count() > MAX_REASON_LENGTH {
+        return Err(DomainError::validation(format!(
+            "Reason must be at most {} characters long!",
+            MAX_REASON_LENGTH
+        )));
     }
+
+    Ok(Some(reason.to_string()))
 }
\ No newline at end of file
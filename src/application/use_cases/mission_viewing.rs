@@ -1,18 +1,19 @@
 use std::sync::Arc;
 
-use anyhow::Result;
-
 use crate::domain::{
+    errors::{DomainError, DomainResult},
     repositories::mission_viewing::MissionViewingRepository,
     value_objects::{
         mission_brawler_model::MissionBrawlerModel,
-        mission_filter::MissionFilter,
+        mission_filter::{
+            DEFAULT_MISSION_PAGE_SIZE, MAX_MISSION_PAGE_SIZE, MissionCursor, MissionFilter,
+            MissionKeyset, MissionPageModel, MissionQuery, MissionSort, parse_timestamp,
+        },
         mission_model::MissionModel,
+        mission_status_history_model::MissionStatusHistoryModel,
     },
 };
 
-
-
 pub struct MissionViewingUseCase<T>
 where
     T: MissionViewingRepository + Send + Sync,
@@ -30,52 +31,176 @@ where
         }
     }
 
-    pub async fn get_one(&self, mission_id: i32) -> Result<MissionModel> {
-        let crew_count = self
-            .mission_viewing_repository
-            .crew_counting(mission_id)
-            .await?;
+    pub async fn get_one(&self, mission_id: i32) -> DomainResult<MissionModel> {
+        let mission_query = MissionQuery {
+            mission_id: Some(mission_id),
+            limit: 1,
+            ..Default::default()
+        };
 
-        let model = self.mission_viewing_repository.get_one(mission_id).await?;
+        let (model, crew_count) = self
+            .mission_viewing_repository
+            .get_with_crew_counts(&mission_query)
+            .await?
+            .pop()
+            .ok_or_else(|| DomainError::not_found("Mission not found"))?;
 
         let result = model.to_model(crew_count);
 
         Ok(result)
     }
 
-    pub async fn get_all(&self, filter: &MissionFilter) -> Result<Vec<MissionModel>> {
-        let models = self.mission_viewing_repository.get_all(filter).await?;
+    /// With a `viewer_id` every mission is marked with the viewer's relation to it.
+    pub async fn get_all(
+        &self,
+        filter: &MissionFilter,
+        viewer_id: Option<i32>,
+    ) -> DomainResult<MissionPageModel> {
+        let mut mission_query = to_query(filter, viewer_id)?;
+        let page_size = mission_query.limit;
+        // One extra row tells whether there is a next page.
+        mission_query.limit += 1;
+
+        let mut models = self
+            .mission_viewing_repository
+            .get_with_crew_counts(&mission_query)
+            .await?;
+
+        let has_next_page = models.len() as i64 > page_size;
+        models.truncate(page_size as usize);
+
+        let joined_mission_ids = match viewer_id {
+            Some(viewer_id) => Some(
+                self.mission_viewing_repository
+                    .joined_mission_ids(viewer_id)
+                    .await?,
+            ),
+            None => None,
+        };
 
         let mut result = Vec::new();
 
-        for model in models.into_iter() {
-            let crew_count = self
-                .mission_viewing_repository
-                .crew_counting(model.id)
-                .await
-                .unwrap_or(0);
+        for (model, crew_count) in models.into_iter() {
+            let mut mission_model = model.to_model(crew_count);
+            if let (Some(viewer_id), Some(joined_mission_ids)) = (viewer_id, &joined_mission_ids) {
+                mission_model.is_chief = Some(model.chief_id == viewer_id);
+                mission_model.is_member = Some(joined_mission_ids.contains(&model.id));
+            }
 
-            result.push(model.to_model(crew_count));
+            result.push(mission_model);
         }
 
+        let next_cursor = match result.last() {
+            Some(last) if has_next_page => Some(
+                MissionCursor::after(last, mission_query.sort_by, mission_query.order).encode()?,
+            ),
+            _ => None,
+        };
+
+        Ok(MissionPageModel {
+            missions: result,
+            next_cursor,
+        })
+    }
+
+    /// The chief comes first, followed by the crew in the order they joined.
+    pub async fn get_mission_brawlers(
+        &self,
+        mission_id: i32,
+    ) -> DomainResult<Vec<MissionBrawlerModel>> {
+        let entities = self
+            .mission_viewing_repository
+            .get_mission_brawlers(mission_id)
+            .await?;
+
+        let result = entities.into_iter().map(|e| e.to_model()).collect();
+
+        Ok(result)
+    }
+
+    pub async fn get_status_history(
+        &self,
+        mission_id: i32,
+    ) -> DomainResult<Vec<MissionStatusHistoryModel>> {
+        let entities = self
+            .mission_viewing_repository
+            .get_status_history(mission_id)
+            .await
+            .map_err(|e| match DomainError::from(e) {
+                DomainError::NotFound(_) => DomainError::not_found("Mission not found"),
+                other => other,
+            })?;
+
+        let result = entities.into_iter().map(|e| e.to_model()).collect();
+
         Ok(result)
     }
-    
-   pub async fn get_mission_count(
-    &self,
-    mission_id: i32,
-) -> Result<Vec<MissionBrawlerModel>> {
-    let entities = self
-        .mission_viewing_repository
-        .get_mission_brawlers(mission_id)
-        .await?;
-
-    let result = entities
-        .into_iter()
-        .map(|e| e.to_model())
-        .collect();
-
-    Ok(result)
 }
 
+fn to_query(filter: &MissionFilter, viewer_id: Option<i32>) -> DomainResult<MissionQuery> {
+    let sort_by = filter.sort_by.unwrap_or_default();
+    let order = filter.order.unwrap_or_default();
+
+    let limit = filter.limit.unwrap_or(DEFAULT_MISSION_PAGE_SIZE);
+    if !(1..=MAX_MISSION_PAGE_SIZE).contains(&limit) {
+        return Err(DomainError::validation(format!(
+            "Limit must be between 1 and {}!",
+            MAX_MISSION_PAGE_SIZE
+        )));
+    }
+
+    let mut statuses = filter.statuses.clone().unwrap_or_default();
+    if let Some(status) = &filter.status {
+        statuses.push(*status);
+    }
+
+    let (joined_by, not_joined_by) = match (filter.joined, viewer_id) {
+        (None, _) => (None, None),
+        (Some(_), None) => {
+            return Err(DomainError::unauthorized(
+                "Log in to filter by the missions you joined!",
+            ));
+        }
+        (Some(true), viewer_id) => (viewer_id, None),
+        (Some(false), viewer_id) => (None, viewer_id),
+    };
+
+    let after = match &filter.cursor {
+        None => None,
+        Some(cursor) => {
+            let invalid_cursor = || DomainError::validation("Invalid cursor!");
+
+            let cursor = MissionCursor::decode(cursor).map_err(|_| invalid_cursor())?;
+            if cursor.sort_by != sort_by || cursor.order != order {
+                return Err(DomainError::validation(
+                    "Cursor was issued for a different sort order!",
+                ));
+            }
+
+            let keyset = match sort_by {
+                MissionSort::CreatedAt | MissionSort::UpdatedAt => MissionKeyset::Timestamp(
+                    parse_timestamp(&cursor.value).map_err(|_| invalid_cursor())?,
+                    cursor.id,
+                ),
+                MissionSort::Name => MissionKeyset::Name(cursor.value, cursor.id),
+            };
+            Some(keyset)
+        }
+    };
+
+    Ok(MissionQuery {
+        mission_id: None,
+        name: filter.name.clone(),
+        statuses,
+        chief_id: filter.chief_id,
+        joined_by,
+        not_joined_by,
+        has_free_slots: filter.has_free_slots,
+        created_after: filter.created_after,
+        created_before: filter.created_before,
+        sort_by,
+        order,
+        after,
+        limit,
+    })
 }
\ No newline at end of file
@@ -1,6 +1,8 @@
-use anyhow::Result;
+use std::str::FromStr;
 
-use crate::config::{config_model::{CloudinaryEnv, Database, DotEnvyConfig, JwtEnv, Server}, stage::Stage};
+use anyhow::{Context, Result};
+
+use crate::config::{config_model::{CloudinaryEnv, Database, DotEnvyConfig, ImageStorageEnv, JwtEnv, JwtKeyEnv, LocalImageStorageEnv, S3Env, Server}, stage::Stage};
 
 pub fn load() -> Result<DotEnvyConfig> {
     dotenvy::dotenv().ok();
@@ -20,14 +22,41 @@ pub fn load() -> Result<DotEnvyConfig> {
 
     let database = Database {
         url: std::env::var("DATABASE_URL").expect("DATABASE_URL not set"),
+        max_connections: std::env::var("DATABASE_MAX_CONNECTIONS")
+            .unwrap_or_else(|_| "10".to_string())
+            .parse()?,
+        min_idle: std::env::var("DATABASE_MIN_IDLE")
+            .ok()
+            .map(|min_idle| min_idle.parse())
+            .transpose()?,
+        connection_timeout: std::env::var("DATABASE_CONNECTION_TIMEOUT")
+            .unwrap_or_else(|_| "30".to_string())
+            .parse()?,
+        idle_timeout: std::env::var("DATABASE_IDLE_TIMEOUT")
+            .ok()
+            .map(|idle_timeout| idle_timeout.parse())
+            .transpose()?,
     };
 
-    let secret = std::env::var("JWT_SECRET").expect("JWT_SECRET not set");
+    let jwt = get_jwt_env()?;
+
+    let max_crew_per_mission = std::env::var("MAX_CREW_PER_MISSION")
+        .unwrap_or_else(|_| "10".to_string())
+        .parse()?;
+
+    let max_avatar_size_kb = std::env::var("MAX_AVATAR_SIZE_KB")
+        .unwrap_or_else(|_| "2048".to_string())
+        .parse()?;
+
+    let image_storage = get_image_storage_env()?;
 
     let config = DotEnvyConfig {
         server,
         database,
-        secret,
+        jwt,
+        max_crew_per_mission,
+        max_avatar_size_kb,
+        image_storage,
     };
 
     Ok(config)
@@ -40,33 +69,94 @@ pub fn get_stage() ->Stage {
 
     Stage::from_str(&stage_str).unwrap_or_default()
 }
-pub fn get_user_secret() -> Result<String>{
-    let secret_env = std::env::var("JWT_SECRET")
-    .map_err(|_| anyhow::anyhow!("JWT_SECRET not set"))?;
-    Ok(secret_env)
-}
-
+fn get_jwt_env() -> Result<JwtEnv> {
+    let signing_key = JwtKeyEnv {
+        key_id: std::env::var("JWT_KEY_ID").unwrap_or_else(|_| "default".to_string()),
+        algorithm: std::env::var("JWT_ALGORITHM").unwrap_or_else(|_| "HS256".to_string()),
+        secret: std::env::var("JWT_USER_SECRET").ok(),
+        private_key_path: std::env::var("JWT_PRIVATE_KEY_PATH").ok(),
+        public_key_path: std::env::var("JWT_PUBLIC_KEY_PATH").ok(),
+    };
 
-// pub fn get_user_secret_env() -> Result<JwtEnv> {
-pub fn get_jwt_env() -> Result<JwtEnv> {
-    dotenvy::dotenv().ok();
+    // Comma separated `kid:algorithm:public_key_path` entries.
+    let verification_keys = std::env::var("JWT_VERIFICATION_KEYS")
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let mut parts = entry.splitn(3, ':');
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some(key_id), Some(algorithm), Some(path)) => Ok(JwtKeyEnv {
+                    key_id: key_id.to_string(),
+                    algorithm: algorithm.to_string(),
+                    secret: None,
+                    private_key_path: None,
+                    public_key_path: Some(path.to_string()),
+                }),
+                _ => Err(anyhow::anyhow!("Invalid JWT_VERIFICATION_KEYS entry: {}", entry)),
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
 
     Ok(JwtEnv {
-        secret: std::env::var("JWT_USER_SECRET")?,
-        lift_time_days: std::env::var("JTW_LIFTTIME_DAYS")?.parse::<i64>()?,
+        signing_key,
+        verification_keys,
+        access_token_lifetime_minutes: std::env::var("JWT_ACCESS_TOKEN_LIFETIME_MINUTES")?
+            .parse::<i64>()?,
+        refresh_token_lifetime_days: std::env::var("JWT_REFRESH_TOKEN_LIFETIME_DAYS")?
+            .parse::<i64>()?,
     })
 }
 
 pub fn get_cloudinary_env() -> Result<CloudinaryEnv> {
     dotenvy::dotenv().ok();
 
-    let cloud_name = std::env::var("CLOUDINARY_CLOUD_NAME")?;
-    let api_key = std::env::var("CLOUDINARY_API_KEY")?;
-    let api_secret = std::env::var("CLOUDINARY_API_SECRET")?;
+    let cloud_name = std::env::var("CLOUDINARY_CLOUD_NAME").context("CLOUDINARY_CLOUD_NAME is not set")?;
+    let api_key = std::env::var("CLOUDINARY_API_KEY").context("CLOUDINARY_API_KEY is not set")?;
+    let api_secret = std::env::var("CLOUDINARY_API_SECRET").context("CLOUDINARY_API_SECRET is not set")?;
+
+    let base_url = std::env::var("CLOUDINARY_BASE_URL")
+        .unwrap_or_else(|_| "https://api.cloudinary.com".to_string());
+    let timeout = std::env::var("CLOUDINARY_TIMEOUT")
+        .unwrap_or_else(|_| "30".to_string())
+        .parse()?;
+    let max_retries = std::env::var("CLOUDINARY_MAX_RETRIES")
+        .unwrap_or_else(|_| "3".to_string())
+        .parse()?;
 
     Ok(CloudinaryEnv {
         cloud_name,
         api_key,
         api_secret,
+        base_url,
+        timeout,
+        max_retries,
     })
+}
+
+fn get_image_storage_env() -> Result<ImageStorageEnv> {
+    let storage = std::env::var("IMAGE_STORAGE").unwrap_or_else(|_| "cloudinary".to_string());
+
+    match storage.to_lowercase().as_str() {
+        "cloudinary" => Ok(ImageStorageEnv::Cloudinary(get_cloudinary_env()?)),
+        "local" => Ok(ImageStorageEnv::Local(LocalImageStorageEnv {
+            dir: std::env::var("IMAGE_STORAGE_DIR")
+                .unwrap_or_else(|_| "statics/uploads".to_string()),
+            base_url: std::env::var("IMAGE_STORAGE_BASE_URL")
+                .unwrap_or_else(|_| "/uploads".to_string()),
+        })),
+        "s3" => Ok(ImageStorageEnv::S3(S3Env {
+            endpoint: std::env::var("S3_ENDPOINT").context("S3_ENDPOINT is not set")?,
+            region: std::env::var("S3_REGION").unwrap_or_else(|_| "us-east-1".to_string()),
+            bucket: std::env::var("S3_BUCKET").context("S3_BUCKET is not set")?,
+            access_key_id: std::env::var("S3_ACCESS_KEY_ID").context("S3_ACCESS_KEY_ID is not set")?,
+            secret_access_key: std::env::var("S3_SECRET_ACCESS_KEY").context("S3_SECRET_ACCESS_KEY is not set")?,
+            public_url: std::env::var("S3_PUBLIC_URL").ok(),
+            timeout: std::env::var("S3_TIMEOUT")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse()?,
+        })),
+        other => Err(anyhow::anyhow!("Unknown IMAGE_STORAGE: {}", other)),
+    }
 }
\ No newline at end of file
@@ -8,21 +8,43 @@ pub struct Server {
 #[derive(Debug, Clone)]
 pub struct Database {
     pub url: String,
+    pub max_connections: u32,
+    pub min_idle: Option<u32>,
+    /// Seconds to wait for a free connection before giving up.
+    pub connection_timeout: u64,
+    /// Seconds before an unused connection above `min_idle` is closed.
+    pub idle_timeout: Option<u64>,
 }
 
 #[derive(Debug, Clone)]
 pub struct DotEnvyConfig {
     pub server: Server,
     pub database: Database,
-    pub secret: String,
-    // pub max_crew_per_mission: u32,
+    pub jwt: JwtEnv,
+    /// Upper bound for the crew size chiefs can choose for their missions.
+    pub max_crew_per_mission: u32,
+    /// Largest avatar accepted, in KiB of decoded image data.
+    pub max_avatar_size_kb: usize,
+    pub image_storage: ImageStorageEnv,
 }
 
 #[derive(Debug, Clone)]
 pub struct JwtEnv {
-    //pub struct UserSecret {
-    pub secret: String,
-    pub lift_time_days: i64,
+    pub signing_key: JwtKeyEnv,
+    /// Retired keys that are still accepted while their tokens expire.
+    pub verification_keys: Vec<JwtKeyEnv>,
+    pub access_token_lifetime_minutes: i64,
+    pub refresh_token_lifetime_days: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct JwtKeyEnv {
+    pub key_id: String,
+    pub algorithm: String,
+    /// HS256 only.
+    pub secret: Option<String>,
+    pub private_key_path: Option<String>,
+    pub public_key_path: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -30,4 +52,39 @@ pub struct CloudinaryEnv {
     pub cloud_name: String,
     pub api_key: String,
     pub api_secret: String,
+    /// `https://api.cloudinary.com`, pointed elsewhere by tests.
+    pub base_url: String,
+    /// Seconds a single request may take, retries included separately.
+    pub timeout: u64,
+    /// Retries after a 5xx answer or a failed connection.
+    pub max_retries: u32,
+}
+
+#[derive(Debug, Clone)]
+pub enum ImageStorageEnv {
+    Cloudinary(CloudinaryEnv),
+    Local(LocalImageStorageEnv),
+    S3(S3Env),
+}
+
+#[derive(Debug, Clone)]
+pub struct LocalImageStorageEnv {
+    pub dir: String,
+    /// URL path (served by this server) or absolute URL the files are reachable at.
+    pub base_url: String,
+}
+
+/// Any S3-compatible API, addressed path-style as `{endpoint}/{bucket}/{key}`.
+/// The endpoint may carry a path prefix, e.g. behind a reverse proxy.
+#[derive(Debug, Clone)]
+pub struct S3Env {
+    pub endpoint: String,
+    pub region: String,
+    pub bucket: String,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    /// Defaults to `{endpoint}/{bucket}`, set it when objects are served through a CDN.
+    pub public_url: Option<String>,
+    /// Seconds a single request may take.
+    pub timeout: u64,
 }
\ No newline at end of file
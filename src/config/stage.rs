@@ -1,5 +1,4 @@
-use std::fmt;
-use anyhow::Result;
+use std::{fmt, str::FromStr};
 
 #[derive(Debug, Clone, Default, PartialEq)]
 
@@ -21,9 +20,11 @@ impl fmt::Display for Stage {
     }
 }
 
-impl Stage {
-    pub fn from_str(stage: &str) ->Result<Self> {
-        match stage {
+impl FromStr for Stage {
+    type Err = anyhow::Error;
+
+    fn from_str(stage: &str) -> Result<Self, Self::Err> {
+        match stage.to_lowercase().as_str() {
             "local" => Ok(Self::Local),
             "development" => Ok(Self::Development),
             "production" => Ok(Self::Production),
@@ -1,17 +1,29 @@
-use crate::domain::value_objects::mission_brawler_model::MissionBrawlerModel;
+use chrono::NaiveDateTime;
 
+use crate::domain::value_objects::{
+    mission_brawler_model::MissionBrawlerModel, uploaded_image::AvatarSizes,
+};
 
 #[derive(Debug, Clone)]
 pub struct BrawlerViewEntity {
     pub id: i32,
-    pub username: String,
+    pub display_name: String,
+    pub avatar_url: Option<String>,
+    pub avatar_sizes: AvatarSizes,
+    /// For the chief this is when the mission was created.
+    pub joined_at: NaiveDateTime,
+    pub is_chief: bool,
 }
 
 impl BrawlerViewEntity {
     pub fn to_model(&self) -> MissionBrawlerModel {
         MissionBrawlerModel {
             id: self.id,
-            username: self.username.clone(),
+            display_name: self.display_name.clone(),
+            avatar_url: self.avatar_url.clone(),
+            avatar_sizes: self.avatar_sizes.clone(),
+            joined_at: self.joined_at,
+            is_chief: self.is_chief,
         }
     }
 }
@@ -1,4 +1,7 @@
-use crate::infrastructure::database::schema::brawlers;
+use crate::{
+    domain::value_objects::{brawler_model::BrawlerProfileModel, uploaded_image::AvatarSizes},
+    infrastructure::database::schema::brawlers,
+};
 use chrono::NaiveDateTime;
 use diesel::{Selectable, prelude::*};
 
@@ -13,7 +16,23 @@ pub struct BrawlerEntity {
     pub display_name: String,
     pub avatar_url: Option<String>,
     pub avatar_public_id: Option<String>,
+    pub role: String,
+    pub avatar_sizes: AvatarSizes,
+}
 
+impl BrawlerEntity {
+    pub fn to_profile_model(&self) -> BrawlerProfileModel {
+        BrawlerProfileModel {
+            id: self.id,
+            username: self.username.clone(),
+            display_name: self.display_name.clone(),
+            avatar_url: self.avatar_url.clone(),
+            avatar_sizes: self.avatar_sizes.clone(),
+            role: self.role.clone(),
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Insertable)]
@@ -22,4 +41,10 @@ pub struct RegisterBrawlerEntity {
     pub username: String,
     pub password: String,
     pub display_name: String,
+}
+
+#[derive(Debug, Clone, AsChangeset)]
+#[diesel(table_name = brawlers)]
+pub struct EditBrawlerEntity {
+    pub display_name: Option<String>,
 }
\ No newline at end of file
@@ -0,0 +1,44 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::{
+    domain::value_objects::{
+        mission_status_history_model::MissionStatusHistoryModel, mission_statuses::MissionStatuses,
+    },
+    infrastructure::database::schema::mission_status_history,
+};
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = mission_status_history)]
+pub struct MissionStatusHistoryEntity {
+    pub id: i32,
+    pub mission_id: i32,
+    pub from_status: MissionStatuses,
+    pub to_status: MissionStatuses,
+    pub actor_id: i32,
+    pub reason: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+impl MissionStatusHistoryEntity {
+    pub fn to_model(&self) -> MissionStatusHistoryModel {
+        MissionStatusHistoryModel {
+            from_status: self.from_status,
+            to_status: self.to_status,
+            actor_id: self.actor_id,
+            reason: self.reason.clone(),
+            created_at: self.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = mission_status_history)]
+pub struct AddMissionStatusHistoryEntity {
+    pub mission_id: i32,
+    pub from_status: MissionStatuses,
+    pub to_status: MissionStatuses,
+    pub actor_id: i32,
+    pub reason: Option<String>,
+}
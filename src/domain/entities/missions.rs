@@ -2,7 +2,8 @@ use chrono::NaiveDateTime;
 use diesel::prelude::*;
 
 use crate::{
-    domain::value_objects::mission_model::MissionModel, infrastructure::database::schema::missions,
+    domain::value_objects::{mission_model::MissionModel, mission_statuses::MissionStatuses},
+    infrastructure::database::schema::missions,
 };
 
 #[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
@@ -12,11 +13,13 @@ pub struct MissionEntity {
     pub id: i32,
     pub name: String,
     pub description: Option<String>,
-    pub status: String,
+    pub status: MissionStatuses,
     pub chief_id: i32,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
     pub deleted_at: Option<NaiveDateTime>,
+    pub min_crew: i32,
+    pub max_crew: i32,
 }
 
 impl MissionEntity {
@@ -25,11 +28,15 @@ impl MissionEntity {
             id: self.id,
             name: self.name.clone(),
             description: self.description.clone(),
-            status: self.status.clone(),
+            status: self.status,
             chief_id: self.chief_id,
             crew_count,
+            min_crew: self.min_crew,
+            max_crew: self.max_crew,
             created_at: self.created_at,
             updated_at: self.updated_at,
+            is_chief: None,
+            is_member: None,
         }
     }
 }
@@ -39,8 +46,10 @@ impl MissionEntity {
 pub struct AddMissionEntity {
     pub chief_id: i32,
     pub name: String,
-    pub status: String,
+    pub status: MissionStatuses,
     pub description: Option<String>,
+    pub min_crew: i32,
+    pub max_crew: i32,
 }
 
 #[derive(Debug, Clone, AsChangeset)]
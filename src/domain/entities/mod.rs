@@ -1,4 +1,8 @@
 pub mod brawlers;
 pub mod crew_memberships;
 pub mod missions;
-pub mod brawler_view;
\ No newline at end of file
+pub mod pending_image_deletions;
+pub mod brawler_view;
+pub mod refresh_tokens;
+pub mod revoked_tokens;
+pub mod mission_status_history;
\ No newline at end of file
@@ -0,0 +1,21 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+
+use crate::infrastructure::database::schema::pending_image_deletions;
+
+#[derive(Debug, Clone, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = pending_image_deletions)]
+pub struct PendingImageDeletionEntity {
+    pub public_id: String,
+    /// Failed attempts so far.
+    pub attempts: i32,
+    pub queued_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = pending_image_deletions)]
+pub struct AddPendingImageDeletionEntity {
+    pub public_id: String,
+    pub queued_at: NaiveDateTime,
+}
@@ -0,0 +1,28 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use crate::infrastructure::database::schema::refresh_tokens;
+
+#[derive(Debug, Clone, Identifiable, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = refresh_tokens)]
+pub struct RefreshTokenEntity {
+    pub id: i32,
+    pub brawler_id: i32,
+    pub family_id: Uuid,
+    pub token_hash: String,
+    pub expires_at: NaiveDateTime,
+    pub used_at: Option<NaiveDateTime>,
+    pub revoked_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = refresh_tokens)]
+pub struct AddRefreshTokenEntity {
+    pub brawler_id: i32,
+    pub family_id: Uuid,
+    pub token_hash: String,
+    pub expires_at: NaiveDateTime,
+}
@@ -0,0 +1,23 @@
+use chrono::NaiveDateTime;
+use diesel::prelude::*;
+use uuid::Uuid;
+
+use crate::infrastructure::database::schema::revoked_tokens;
+
+#[derive(Debug, Clone, Selectable, Queryable)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(table_name = revoked_tokens)]
+pub struct RevokedTokenEntity {
+    pub jti: Uuid,
+    pub brawler_id: i32,
+    pub expires_at: NaiveDateTime,
+    pub revoked_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Insertable)]
+#[diesel(table_name = revoked_tokens)]
+pub struct AddRevokedTokenEntity {
+    pub jti: Uuid,
+    pub brawler_id: i32,
+    pub expires_at: NaiveDateTime,
+}
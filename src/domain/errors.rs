@@ -0,0 +1,118 @@
+use std::fmt::Display;
+
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+
+use crate::domain::value_objects::mission_statuses::InvalidTransition;
+
+pub type DomainResult<T> = Result<T, DomainError>;
+
+#[derive(Debug)]
+pub enum DomainError {
+    NotFound(String),
+    Forbidden(String),
+    Conflict(String),
+    Validation(String),
+    PayloadTooLarge(String),
+    Unauthorized(String),
+    Upstream(String),
+    InvalidTransition(InvalidTransition),
+    Internal(anyhow::Error),
+}
+
+impl DomainError {
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::NotFound(message.into())
+    }
+
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self::Forbidden(message.into())
+    }
+
+    pub fn conflict(message: impl Into<String>) -> Self {
+        Self::Conflict(message.into())
+    }
+
+    pub fn validation(message: impl Into<String>) -> Self {
+        Self::Validation(message.into())
+    }
+
+    pub fn payload_too_large(message: impl Into<String>) -> Self {
+        Self::PayloadTooLarge(message.into())
+    }
+
+    pub fn unauthorized(message: impl Into<String>) -> Self {
+        Self::Unauthorized(message.into())
+    }
+
+    pub fn upstream(message: impl Into<String>) -> Self {
+        Self::Upstream(message.into())
+    }
+
+    /// Machine-readable code sent to clients alongside the message.
+    pub fn code(&self) -> &'static str {
+        match self {
+            DomainError::NotFound(_) => "NOT_FOUND",
+            DomainError::Forbidden(_) => "FORBIDDEN",
+            DomainError::Conflict(_) => "CONFLICT",
+            DomainError::Validation(_) => "VALIDATION_FAILED",
+            DomainError::PayloadTooLarge(_) => "PAYLOAD_TOO_LARGE",
+            DomainError::Unauthorized(_) => "UNAUTHORIZED",
+            DomainError::Upstream(_) => "UPSTREAM_FAILURE",
+            DomainError::InvalidTransition(_) => "INVALID_TRANSITION",
+            DomainError::Internal(_) => "INTERNAL_ERROR",
+        }
+    }
+}
+
+impl Display for DomainError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DomainError::NotFound(message)
+            | DomainError::Forbidden(message)
+            | DomainError::Conflict(message)
+            | DomainError::Validation(message)
+            | DomainError::PayloadTooLarge(message)
+            | DomainError::Unauthorized(message)
+            | DomainError::Upstream(message) => write!(f, "{}", message),
+            DomainError::InvalidTransition(e) => write!(f, "{}", e),
+            DomainError::Internal(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for DomainError {}
+
+impl From<InvalidTransition> for DomainError {
+    fn from(error: InvalidTransition) -> Self {
+        Self::InvalidTransition(error)
+    }
+}
+
+impl From<anyhow::Error> for DomainError {
+    fn from(error: anyhow::Error) -> Self {
+        // Domain errors that were raised inside a transaction come back wrapped.
+        let error = match error.downcast::<DomainError>() {
+            Ok(domain_error) => return domain_error,
+            Err(error) => error,
+        };
+
+        if let Some(diesel_error) = error.downcast_ref::<DieselError>() {
+            match diesel_error {
+                DieselError::NotFound => return Self::not_found("Resource not found"),
+                DieselError::DatabaseError(DatabaseErrorKind::UniqueViolation, _) => {
+                    return Self::conflict("Resource already exists");
+                }
+                DieselError::DatabaseError(DatabaseErrorKind::ForeignKeyViolation, _) => {
+                    return Self::conflict("Referenced resource does not exist");
+                }
+                _ => {}
+            }
+        }
+
+        if error.downcast_ref::<reqwest::Error>().is_some() {
+            return Self::upstream(error.to_string());
+        }
+
+        Self::Internal(error)
+    }
+}
@@ -1,3 +1,4 @@
 pub mod entities;
+pub mod errors;
 pub mod repositories;
 pub mod value_objects;
\ No newline at end of file
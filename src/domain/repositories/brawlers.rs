@@ -1,15 +1,29 @@
-use crate::{domain::{entities::brawlers::{BrawlerEntity, RegisterBrawlerEntity}, value_objects::{base64_image::Base64Image, uploaded_image::UploadedImage}}, infrastructure::{cloudinary::{UploadImageOptions}}};
+use crate::domain::{entities::brawlers::{BrawlerEntity, EditBrawlerEntity, RegisterBrawlerEntity}, value_objects::{brawler_model::BrawlerModel, leaderboard_filter::LeaderboardSort, roles::Role, uploaded_image::{AvatarSizes, UploadedImage}}};
 use anyhow::Result;
 use async_trait::async_trait;
 
 #[async_trait]
 pub trait BrawlerRepository {
     async fn register(&self, register_brawler_entity: RegisterBrawlerEntity) -> Result<i32>;
-    async fn find_by_username(&self, username: &String) -> Result<BrawlerEntity>;
-    async fn upload_avatar(
+    async fn find_by_username(&self, username: &str) -> Result<BrawlerEntity>;
+    async fn find_by_id(&self, brawler_id: i32) -> Result<BrawlerEntity>;
+    async fn find_profile(&self, brawler_id: i32) -> Result<BrawlerModel>;
+    async fn leaderboard(
+        &self,
+        sort_by: LeaderboardSort,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<BrawlerModel>>;
+    async fn edit(&self, brawler_id: i32, edit_brawler_entity: EditBrawlerEntity) -> Result<()>;
+    async fn update_password(&self, brawler_id: i32, hashed_password: String) -> Result<()>;
+    async fn update_role(&self, brawler_id: i32, role: Role) -> Result<()>;
+    /// Points the brawler's avatar at images already in the image storage,
+    /// `uploaded_image` is the largest of `avatar_sizes`.
+    async fn update_avatar(
         &self,
         brawler_id: i32,
-        base64_image: Base64Image,
-        option: UploadImageOptions,
-    ) -> Result<UploadedImage>;
+        uploaded_image: UploadedImage,
+        avatar_sizes: AvatarSizes,
+    ) -> Result<()>;
+    async fn remove_avatar(&self, brawler_id: i32) -> Result<()>;
 }
\ No newline at end of file
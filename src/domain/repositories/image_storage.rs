@@ -0,0 +1,21 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::value_objects::{
+    base64_image::Base64Image,
+    uploaded_image::{UploadImageOptions, UploadedImage},
+};
+
+/// Where uploaded images live, chosen with `IMAGE_STORAGE`.
+#[async_trait]
+pub trait ImageStorage {
+    /// Stores the image, replacing any earlier one with the same folder and public id.
+    async fn upload(
+        &self,
+        base64_image: Base64Image,
+        option: UploadImageOptions,
+    ) -> Result<UploadedImage>;
+    /// Removes the image `upload` returned `public_id` for, succeeding when it
+    /// is already gone.
+    async fn delete(&self, public_id: &str) -> Result<()>;
+}
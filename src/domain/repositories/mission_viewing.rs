@@ -5,8 +5,9 @@ use crate::domain::{
     entities::{
         missions::MissionEntity,
         brawler_view::BrawlerViewEntity,
+        mission_status_history::MissionStatusHistoryEntity,
     },
-    value_objects::mission_filter::MissionFilter,
+    value_objects::mission_filter::MissionQuery,
 };
 
 #[async_trait]
@@ -15,13 +16,22 @@ pub trait MissionViewingRepository {
 
     async fn get_one(&self, mission_id: i32) -> Result<MissionEntity>;
 
-    async fn get_all(
+    /// Missions matching the query, each with its crew count.
+    async fn get_with_crew_counts(
         &self,
-        mission_filter: &MissionFilter,
-    ) -> Result<Vec<MissionEntity>>;
+        mission_query: &MissionQuery,
+    ) -> Result<Vec<(MissionEntity, i64)>>;
+
+    async fn joined_mission_ids(&self, brawler_id: i32) -> Result<Vec<i32>>;
 
     async fn get_mission_brawlers(
         &self,
         mission_id: i32,
     ) -> Result<Vec<BrawlerViewEntity>>;
+
+    /// Oldest transition first.
+    async fn get_status_history(
+        &self,
+        mission_id: i32,
+    ) -> Result<Vec<MissionStatusHistoryEntity>>;
 }
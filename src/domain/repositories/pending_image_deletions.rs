@@ -0,0 +1,16 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::entities::pending_image_deletions::PendingImageDeletionEntity;
+
+/// Images waiting to be deleted from the image storage, see `ImageCleanupQueue`.
+#[async_trait]
+pub trait PendingImageDeletionRepository {
+    /// Queues the images, those already queued keep their attempts.
+    async fn add(&self, public_ids: Vec<String>) -> Result<()>;
+    /// Every queued image, oldest first.
+    async fn pending(&self) -> Result<Vec<PendingImageDeletionEntity>>;
+    /// Counts a failed attempt and returns the attempts so far.
+    async fn record_failure(&self, public_id: &str) -> Result<i32>;
+    async fn remove(&self, public_id: &str) -> Result<()>;
+}
@@ -0,0 +1,20 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::refresh_tokens::{AddRefreshTokenEntity, RefreshTokenEntity};
+
+#[async_trait]
+pub trait RefreshTokenRepository {
+    async fn add(&self, add_refresh_token_entity: AddRefreshTokenEntity) -> Result<()>;
+    async fn find_by_hash(&self, token_hash: &str) -> Result<RefreshTokenEntity>;
+    /// Marks `used_token_id` as used and stores its replacement in one transaction.
+    /// Returns `false` when the token had already been used or revoked.
+    async fn rotate(
+        &self,
+        used_token_id: i32,
+        add_refresh_token_entity: AddRefreshTokenEntity,
+    ) -> Result<bool>;
+    async fn revoke_family(&self, family_id: Uuid) -> Result<()>;
+    async fn revoke_all(&self, brawler_id: i32) -> Result<()>;
+}
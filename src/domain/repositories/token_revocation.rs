@@ -0,0 +1,15 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+
+use crate::domain::entities::revoked_tokens::{AddRevokedTokenEntity, RevokedTokenEntity};
+
+#[async_trait]
+pub trait TokenRevocationRepository {
+    async fn revoke(&self, add_revoked_token_entity: AddRevokedTokenEntity) -> Result<()>;
+    /// Invalidates every token issued to the brawler up to now and returns the cutoff.
+    async fn revoke_all(&self, brawler_id: i32) -> Result<NaiveDateTime>;
+    async fn active_revocations(&self) -> Result<Vec<RevokedTokenEntity>>;
+    async fn session_cutoffs(&self) -> Result<Vec<(i32, NaiveDateTime)>>;
+    async fn remove_expired(&self) -> Result<usize>;
+}
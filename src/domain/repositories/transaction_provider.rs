@@ -0,0 +1,39 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::domain::{
+    entities::{
+        crew_memberships::CrewMembershipEntity, mission_status_history::AddMissionStatusHistoryEntity,
+        missions::{EditMissionEntity, MissionEntity},
+    },
+    errors::DomainResult,
+};
+
+/// Mission and crew changes made inside a single database transaction.
+pub trait MissionTransaction {
+    /// Locks the mission row until the transaction ends, so concurrent joins,
+    /// leaves and status changes of the same mission run one after another.
+    fn lock_mission(&mut self, mission_id: i32) -> Result<MissionEntity>;
+    fn crew_count(&mut self, mission_id: i32) -> Result<i64>;
+    /// Updates an open mission. Fails with `NotFound` if it is gone or no longer open.
+    fn edit_mission(&mut self, mission_id: i32, edit_mission_entity: EditMissionEntity) -> Result<i32>;
+    /// Soft-deletes an open mission. Fails with `NotFound` if it is gone or no longer open.
+    fn remove_mission(&mut self, mission_id: i32, chief_id: i32) -> Result<()>;
+    fn join(&mut self, crew_membership_entity: CrewMembershipEntity) -> Result<()>;
+    fn leave(&mut self, crew_membership_entity: CrewMembershipEntity) -> Result<()>;
+    /// Moves the mission from `from_status` to `to_status` and records it in
+    /// the status history. Fails with `NotFound` if the mission is no longer
+    /// in `from_status`.
+    fn change_status(&mut self, transition: AddMissionStatusHistoryEntity) -> Result<()>;
+}
+
+#[async_trait]
+pub trait TransactionProvider {
+    type Transaction: MissionTransaction;
+
+    /// Commits when `f` returns `Ok` and rolls back otherwise.
+    async fn transaction<T, F>(&self, f: F) -> DomainResult<T>
+    where
+        F: FnOnce(&mut Self::Transaction) -> DomainResult<T> + Send + 'static,
+        T: Send + 'static;
+}
@@ -17,17 +17,65 @@ impl Base64Image {
             Err(_) => return Err(anyhow::anyhow!("Invalid base64 image data.")),
         };
 
-        let file_type = match infer::get(&bytes) {
-            Some(t) if t.mime_type() == "image/png" || t.mime_type() == "image/jpeg" => {
-                t.mime_type()
-            }
-            _ => return Err(anyhow::anyhow!("Unsupported or invalid base64 image type.")),
-        };
+        let file_type = Self::sniff_mime_type(&bytes)?;
 
         Ok(Self(format!("data:{};base64,{}", file_type, data)))
     }
 
+    /// For raw uploads, applies the same type checks as [`Base64Image::new`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        if bytes.is_empty() {
+            return Err(anyhow::anyhow!("Image data cannot be empty !!"));
+        }
+
+        let file_type = Self::sniff_mime_type(bytes)?;
+
+        Ok(Self(format!(
+            "data:{};base64,{}",
+            file_type,
+            general_purpose::STANDARD.encode(bytes)
+        )))
+    }
+
+    /// The MIME type of a supported image, judged by its leading bytes only.
+    pub fn sniff_mime_type(bytes: &[u8]) -> Result<&'static str> {
+        match infer::get(bytes) {
+            Some(t) if matches!(t.mime_type(), "image/png" | "image/jpeg" | "image/webp") => {
+                Ok(t.mime_type())
+            }
+            _ => Err(anyhow::anyhow!(
+                "Unsupported or invalid image type, expected PNG, JPEG or WebP."
+            )),
+        }
+    }
+
     pub fn into_inner(self) -> String {
         self.0
     }
+
+    pub fn mime_type(&self) -> &str {
+        self.0
+            .strip_prefix("data:")
+            .and_then(|data_url| data_url.split_once(";base64,"))
+            .map(|(mime_type, _)| mime_type)
+            .unwrap_or_default()
+    }
+
+    /// File extension for the image type, without the dot.
+    pub fn extension(&self) -> &'static str {
+        match self.mime_type() {
+            "image/png" => "png",
+            "image/webp" => "webp",
+            _ => "jpg",
+        }
+    }
+
+    pub fn decode(&self) -> Result<Vec<u8>> {
+        let (_, data) = self
+            .0
+            .split_once(";base64,")
+            .ok_or_else(|| anyhow::anyhow!("Invalid base64 image data."))?;
+
+        Ok(general_purpose::STANDARD.decode(data)?)
+    }
 }
\ No newline at end of file
@@ -1,7 +1,11 @@
-use diesel::{prelude::QueryableByName, sql_types::{BigInt, Varchar}};
+use chrono::NaiveDateTime;
+use diesel::{prelude::QueryableByName, sql_types::{BigInt, Integer, Jsonb, Nullable, Varchar}};
 use serde::{Deserialize, Serialize};
 
-use crate::domain::entities::brawlers::RegisterBrawlerEntity;
+use crate::domain::{
+    entities::brawlers::{EditBrawlerEntity, RegisterBrawlerEntity},
+    value_objects::{roles::Role, uploaded_image::AvatarSizes},
+};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RegisterBrawlerModel {
@@ -22,14 +26,59 @@ impl RegisterBrawlerModel {
     }
 }
 
+/// What a brawler sees of their own account.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BrawlerProfileModel {
+    pub id: i32,
+    pub username: String,
+    pub display_name: String,
+    pub avatar_url: Option<String>,
+    pub avatar_sizes: AvatarSizes,
+    pub role: String,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EditBrawlerModel {
+    pub display_name: Option<String>,
+}
+
+impl EditBrawlerModel {
+    pub fn to_entity(&self) -> EditBrawlerEntity {
+        EditBrawlerEntity {
+            display_name: self.display_name.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangePasswordModel {
+    pub current_password: String,
+    pub new_password: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChangeRoleModel {
+    pub role: Role,
+}
+
 #[derive(Debug, Clone, Serialize,Deserialize, QueryableByName)]
 pub struct BrawlerModel {
+    #[diesel(sql_type = Integer)]
+    pub id: i32,
     #[diesel(sql_type = Varchar)]
     pub display_name: String,
-    #[diesel(sql_type = Varchar)]
-    pub avatar_url: String,
+    #[diesel(sql_type = Nullable<Varchar>)]
+    pub avatar_url: Option<String>,
+    #[diesel(sql_type = Jsonb)]
+    pub avatar_sizes: AvatarSizes,
     #[diesel(sql_type = BigInt)]
     pub mission_success_count: i64,
     #[diesel(sql_type = BigInt)]
     pub mission_joined_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub mission_failed_count: i64,
+    #[diesel(sql_type = BigInt)]
+    pub mission_led_count: i64,
 }
\ No newline at end of file
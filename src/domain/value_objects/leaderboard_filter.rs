@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::brawler_model::BrawlerModel;
+
+pub const DEFAULT_LEADERBOARD_PAGE_SIZE: i64 = 20;
+pub const MAX_LEADERBOARD_PAGE_SIZE: i64 = 100;
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum LeaderboardSort {
+    #[default]
+    Success,
+    Joined,
+    Failed,
+    Led,
+}
+
+impl LeaderboardSort {
+    /// Column of the statistics query to order by, never taken from user input.
+    pub fn column(&self) -> &'static str {
+        match self {
+            LeaderboardSort::Success => "mission_success_count",
+            LeaderboardSort::Joined => "mission_joined_count",
+            LeaderboardSort::Failed => "mission_failed_count",
+            LeaderboardSort::Led => "mission_led_count",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct LeaderboardFilter {
+    pub sort_by: Option<LeaderboardSort>,
+    pub page: Option<i64>,
+    pub page_size: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderboardModel {
+    pub sort_by: LeaderboardSort,
+    pub page: i64,
+    pub page_size: i64,
+    pub brawlers: Vec<BrawlerModel>,
+}
@@ -1,7 +1,14 @@
+use chrono::NaiveDateTime;
 use serde::{Serialize, Deserialize};
 
+use crate::domain::value_objects::uploaded_image::AvatarSizes;
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MissionBrawlerModel {
     pub id: i32,
-    pub username: String,
+    pub display_name: String,
+    pub avatar_url: Option<String>,
+    pub avatar_sizes: AvatarSizes,
+    pub joined_at: NaiveDateTime,
+    pub is_chief: bool,
 }
@@ -1,9 +1,139 @@
-use serde::{Deserialize, Serialize};
+use anyhow::Result;
+use base64::{Engine as _, engine::general_purpose};
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Deserializer, Serialize, de::IntoDeserializer};
 
-use crate::domain::value_objects::mission_statuses::MissionStatuses;
+use crate::domain::value_objects::{mission_model::MissionModel, mission_statuses::MissionStatuses};
+
+pub const DEFAULT_MISSION_PAGE_SIZE: i64 = 20;
+pub const MAX_MISSION_PAGE_SIZE: i64 = 100;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct MissionFilter {
     pub name: Option<String>,
     pub status: Option<MissionStatuses>,
+    /// Comma separated, e.g. `statuses=Open,Failed`.
+    #[serde(default, deserialize_with = "comma_separated")]
+    pub statuses: Option<Vec<MissionStatuses>>,
+    pub chief_id: Option<i32>,
+    /// Needs a bearer token, `false` lists the missions the caller has not joined.
+    pub joined: Option<bool>,
+    pub has_free_slots: Option<bool>,
+    pub created_after: Option<NaiveDateTime>,
+    pub created_before: Option<NaiveDateTime>,
+    pub sort_by: Option<MissionSort>,
+    pub order: Option<SortOrder>,
+    /// `next_cursor` of the previous page.
+    pub cursor: Option<String>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum MissionSort {
+    #[default]
+    CreatedAt,
+    UpdatedAt,
+    Name,
+}
+
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    Asc,
+    #[default]
+    Desc,
+}
+
+/// Position after the last mission of a page, opaque to clients.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissionCursor {
+    pub sort_by: MissionSort,
+    pub order: SortOrder,
+    pub value: String,
+    pub id: i32,
+}
+
+impl MissionCursor {
+    pub fn after(mission_model: &MissionModel, sort_by: MissionSort, order: SortOrder) -> Self {
+        let value = match sort_by {
+            MissionSort::CreatedAt => format_timestamp(&mission_model.created_at),
+            MissionSort::UpdatedAt => format_timestamp(&mission_model.updated_at),
+            MissionSort::Name => mission_model.name.clone(),
+        };
+
+        Self {
+            sort_by,
+            order,
+            value,
+            id: mission_model.id,
+        }
+    }
+
+    pub fn encode(&self) -> Result<String> {
+        Ok(general_purpose::URL_SAFE_NO_PAD.encode(serde_json::to_vec(self)?))
+    }
+
+    pub fn decode(cursor: &str) -> Result<Self> {
+        let bytes = general_purpose::URL_SAFE_NO_PAD.decode(cursor)?;
+
+        Ok(serde_json::from_slice(&bytes)?)
+    }
+}
+
+/// Keyset bound on `(sort column, id)` taken from a decoded cursor.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MissionKeyset {
+    Timestamp(NaiveDateTime, i32),
+    Name(String, i32),
+}
+
+/// A validated [`MissionFilter`] for the caller, as handed to the repository.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MissionQuery {
+    pub mission_id: Option<i32>,
+    pub name: Option<String>,
+    pub statuses: Vec<MissionStatuses>,
+    pub chief_id: Option<i32>,
+    pub joined_by: Option<i32>,
+    pub not_joined_by: Option<i32>,
+    /// Compares the crew count with each mission's own `max_crew`.
+    pub has_free_slots: Option<bool>,
+    pub created_after: Option<NaiveDateTime>,
+    pub created_before: Option<NaiveDateTime>,
+    pub sort_by: MissionSort,
+    pub order: SortOrder,
+    pub after: Option<MissionKeyset>,
+    pub limit: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MissionPageModel {
+    pub missions: Vec<MissionModel>,
+    pub next_cursor: Option<String>,
+}
+
+pub fn format_timestamp(timestamp: &NaiveDateTime) -> String {
+    timestamp.format("%Y-%m-%dT%H:%M:%S%.6f").to_string()
+}
+
+pub fn parse_timestamp(timestamp: &str) -> Result<NaiveDateTime> {
+    Ok(NaiveDateTime::parse_from_str(timestamp, "%Y-%m-%dT%H:%M:%S%.f")?)
+}
+
+fn comma_separated<'de, D>(deserializer: D) -> Result<Option<Vec<MissionStatuses>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let Some(statuses) = Option::<String>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+
+    statuses
+        .split(',')
+        .map(str::trim)
+        .filter(|status| !status.is_empty())
+        .map(|status| MissionStatuses::deserialize(status.into_deserializer()))
+        .collect::<Result<Vec<_>, _>>()
+        .map(Some)
 }
\ No newline at end of file
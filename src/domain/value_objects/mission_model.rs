@@ -11,26 +11,39 @@ pub struct MissionModel {
     pub id: i32,
     pub name: String,
     pub description: Option<String>,
-    pub status: String,
+    pub status: MissionStatuses,
     pub chief_id: i32,
     pub crew_count: i64,
+    pub min_crew: i32,
+    pub max_crew: i32,
     pub created_at: NaiveDateTime,
     pub updated_at: NaiveDateTime,
+    // Only filled in when the listing is requested with a bearer token.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_chief: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_member: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AddMissionModel {
     pub name: String,
     pub description: Option<String>,
+    /// Crew needed to start the mission, defaults to 1.
+    pub min_crew: Option<i32>,
+    /// Defaults to the server-wide limit.
+    pub max_crew: Option<i32>,
 }
 
 impl AddMissionModel {
-    pub fn to_entity(&self, chief_id: i32) -> AddMissionEntity {
+    pub fn to_entity(&self, chief_id: i32, min_crew: i32, max_crew: i32) -> AddMissionEntity {
         AddMissionEntity {
             name: self.name.clone(),
             description: self.description.clone(),
-            status: MissionStatuses::Open.to_string(),
+            status: MissionStatuses::Open,
             chief_id,
+            min_crew,
+            max_crew,
         }
     }
 }
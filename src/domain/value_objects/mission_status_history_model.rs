@@ -0,0 +1,19 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::value_objects::mission_statuses::MissionStatuses;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct MissionStatusHistoryModel {
+    pub from_status: MissionStatuses,
+    pub to_status: MissionStatuses,
+    pub actor_id: i32,
+    pub reason: Option<String>,
+    pub created_at: NaiveDateTime,
+}
+
+/// Optional body of the status change endpoints.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ChangeStatusModel {
+    pub reason: Option<String>,
+}
@@ -1,14 +1,71 @@
-use std::fmt::Display;
+use std::{fmt::Display, str::FromStr};
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub enum MissionStatuses {
     #[default]
     Open,
     InProgress,
     Completed,
     Failed,
+    Cancelled,
+}
+
+impl MissionStatuses {
+    /// The allowed moves between statuses:
+    ///
+    /// - `Open` -> `InProgress`, `Cancelled`
+    /// - `InProgress` -> `Completed`, `Failed`
+    /// - `Failed` -> `Open` (re-open), `InProgress` (retry), `Cancelled`
+    /// - `Completed` and `Cancelled` are final.
+    pub fn can_transition_to(&self, next: MissionStatuses) -> bool {
+        use MissionStatuses::*;
+
+        matches!(
+            (self, next),
+            (Open, InProgress | Cancelled)
+                | (InProgress, Completed | Failed)
+                | (Failed, Open | InProgress | Cancelled)
+        )
+    }
+
+    /// Admins may additionally fail a mission that never started.
+    pub fn can_admin_transition_to(&self, next: MissionStatuses) -> bool {
+        self.can_transition_to(next)
+            || matches!((self, next), (MissionStatuses::Open, MissionStatuses::Failed))
+    }
+
+    pub fn transition_to(self, next: MissionStatuses) -> Result<MissionStatuses, InvalidTransition> {
+        self.checked_transition(next, self.can_transition_to(next))
+    }
+
+    pub fn admin_transition_to(
+        self,
+        next: MissionStatuses,
+    ) -> Result<MissionStatuses, InvalidTransition> {
+        self.checked_transition(next, self.can_admin_transition_to(next))
+    }
+
+    fn checked_transition(
+        self,
+        next: MissionStatuses,
+        allowed: bool,
+    ) -> Result<MissionStatuses, InvalidTransition> {
+        if !allowed {
+            return Err(InvalidTransition {
+                from: self,
+                to: next,
+            });
+        }
+
+        Ok(next)
+    }
+
+    /// Crew can only come and go before the mission starts or after it failed.
+    pub fn is_joinable(&self) -> bool {
+        matches!(self, MissionStatuses::Open | MissionStatuses::Failed)
+    }
 }
 
 impl Display for MissionStatuses {
@@ -18,6 +75,37 @@ impl Display for MissionStatuses {
             MissionStatuses::InProgress => write!(f, "InProgress"),
             MissionStatuses::Completed => write!(f, "Completed"),
             MissionStatuses::Failed => write!(f, "Failed"),
+            MissionStatuses::Cancelled => write!(f, "Cancelled"),
         }
     }
-}
\ No newline at end of file
+}
+
+impl FromStr for MissionStatuses {
+    type Err = anyhow::Error;
+
+    fn from_str(status: &str) -> Result<Self, Self::Err> {
+        match status {
+            "Open" => Ok(Self::Open),
+            "InProgress" => Ok(Self::InProgress),
+            "Completed" => Ok(Self::Completed),
+            "Failed" => Ok(Self::Failed),
+            "Cancelled" => Ok(Self::Cancelled),
+            _ => Err(anyhow::anyhow!("Invalid mission status: {}", status)),
+        }
+    }
+}
+
+/// A status change the state machine does not allow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidTransition {
+    pub from: MissionStatuses,
+    pub to: MissionStatuses,
+}
+
+impl Display for InvalidTransition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Mission cannot move from {} to {}!", self.from, self.to)
+    }
+}
+
+impl std::error::Error for InvalidTransition {}
\ No newline at end of file
@@ -1,8 +1,10 @@
 pub mod brawler_model;
+pub mod leaderboard_filter;
 pub mod mission_filter;
 pub mod mission_model;
 pub mod mission_statuses;
 pub mod uploaded_image;
 pub mod base64_image;
 pub mod mission_brawler_model;
-
+pub mod roles;
+pub mod mission_status_history_model;
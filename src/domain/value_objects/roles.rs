@@ -0,0 +1,36 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+/// Ordered from least to most privileged, so `role >= Role::Moderator` reads naturally.
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum Role {
+    #[default]
+    Brawler,
+    Moderator,
+    Admin,
+}
+
+impl Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Role::Brawler => write!(f, "brawler"),
+            Role::Moderator => write!(f, "moderator"),
+            Role::Admin => write!(f, "admin"),
+        }
+    }
+}
+
+impl FromStr for Role {
+    type Err = anyhow::Error;
+
+    fn from_str(role: &str) -> Result<Self, Self::Err> {
+        match role {
+            "brawler" => Ok(Self::Brawler),
+            "moderator" => Ok(Self::Moderator),
+            "admin" => Ok(Self::Admin),
+            _ => Err(anyhow::anyhow!("Invalid role: {}", role)),
+        }
+    }
+}
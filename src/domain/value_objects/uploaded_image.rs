@@ -13,6 +13,58 @@ impl UploadedImage {
 
 }
 
+/// A processed avatar, `url` and `public_id` are those of the largest size.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UploadedAvatar {
+    pub url: String,
+    pub public_id: String,
+    pub sizes: Vec<AvatarSize>,
+}
+
+impl UploadedAvatar {
+    pub fn avatar_sizes(&self) -> AvatarSizes {
+        AvatarSizes(
+            self.sizes
+                .iter()
+                .map(|size| AvatarUrl {
+                    size: size.size,
+                    url: size.url.clone(),
+                })
+                .collect(),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AvatarSize {
+    /// Width and height in pixels.
+    pub size: u32,
+    pub url: String,
+    pub public_id: String,
+}
+
+/// Every stored size of a brawler's avatar, smallest first. Empty without an
+/// avatar and for avatars stored before sizes were introduced.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct AvatarSizes(pub Vec<AvatarUrl>);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AvatarUrl {
+    /// Width and height in pixels.
+    pub size: u32,
+    pub url: String,
+}
+
+/// Where an image is stored. `public_id` is relative to `folder`.
+#[derive(Debug, Clone, Default)]
+pub struct UploadImageOptions {
+    pub folder: Option<String>,
+    pub public_id: Option<String>,
+    /// Cloudinary transformation string, ignored by the other storages.
+    pub transformation: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UploadAvatar {
 pub base64_string: String,
@@ -0,0 +1,79 @@
+use std::fmt::Display;
+
+use reqwest::StatusCode;
+use serde::Deserialize;
+
+use crate::domain::errors::DomainError;
+
+/// Body Cloudinary answers failed requests with: `{"error": {"message": "..."}}`.
+#[derive(Debug, Deserialize)]
+pub struct ErrorPayload {
+    pub error: ErrorMessage,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ErrorMessage {
+    pub message: String,
+}
+
+#[derive(Debug)]
+pub enum CloudinaryError {
+    /// Cloudinary rejected the request and said why.
+    Api { status: StatusCode, message: String },
+    /// The request could not be sent, or no answer arrived in time.
+    Transport(reqwest::Error),
+    /// An answer that is neither the expected result nor an error payload.
+    UnexpectedResponse { status: StatusCode, body: String },
+    /// `destroy` answered with something other than `ok` or `not found`.
+    NotDestroyed { public_id: String, result: String },
+}
+
+impl CloudinaryError {
+    /// Server side failures and lost connections may go away on their own.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            CloudinaryError::Api { status, .. }
+            | CloudinaryError::UnexpectedResponse { status, .. } => status.is_server_error(),
+            CloudinaryError::Transport(e) => e.is_timeout() || e.is_connect() || e.is_request(),
+            CloudinaryError::NotDestroyed { .. } => false,
+        }
+    }
+}
+
+impl Display for CloudinaryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CloudinaryError::Api { status, message } => {
+                write!(f, "Cloudinary answered {}: {}", status, message)
+            }
+            CloudinaryError::Transport(e) => write!(f, "Cloudinary request failed: {}", e),
+            CloudinaryError::UnexpectedResponse { status, body } => {
+                write!(f, "Unexpected Cloudinary response {}: {}", status, body)
+            }
+            CloudinaryError::NotDestroyed { public_id, result } => {
+                write!(f, "Cloudinary did not destroy {}: {}", public_id, result)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CloudinaryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CloudinaryError::Transport(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<CloudinaryError> for DomainError {
+    fn from(error: CloudinaryError) -> Self {
+        DomainError::upstream(error.to_string())
+    }
+}
+
+impl From<reqwest::Error> for CloudinaryError {
+    fn from(error: reqwest::Error) -> Self {
+        Self::Transport(error)
+    }
+}
@@ -1,89 +1,199 @@
-use crate::config::{config_loader::get_cloudinary_env, config_model::CloudinaryEnv};
+pub mod cloudinary_error;
+
+use crate::config::config_model::CloudinaryEnv;
 
 use crate::domain::value_objects::base64_image::Base64Image;
-use crate::domain::value_objects::uploaded_image::UploadedImage;
+use crate::domain::value_objects::uploaded_image::{UploadImageOptions, UploadedImage};
+use crate::infrastructure::cloudinary::cloudinary_error::{CloudinaryError, ErrorPayload};
 
-use anyhow::{Context, Result};
+use anyhow::Result;
 use chrono::Utc;
+use reqwest::Client;
 use reqwest::multipart::{Form, Part};
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
 use sha1::{Digest, Sha1};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+use std::time::Duration;
+use tracing::warn;
 
+/// Doubled after every failed attempt.
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(200);
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
 
-pub struct UploadImageOptions {
-    pub folder: Option<String>,
-    pub public_id: Option<String>,
-    pub transformation: Option<String>,
+#[derive(Debug, Deserialize)]
+struct UploadResult {
+    url: String,
+    secure_url: Option<String>,
+    public_id: String,
 }
 
-fn form_builder(option: UploadImageOptions, cloud_env: &CloudinaryEnv) -> Result<Form> {
-    let mut form = Form::new();
-    let timestamp = Utc::now().timestamp_millis().to_string();
-    let mut hasher = Sha1::new();
+#[derive(Debug, Deserialize)]
+struct DestroyResult {
+    result: String,
+}
 
-    let mut params_to_sign: HashMap<String, String> = HashMap::new();
-    params_to_sign.insert("resource_type".to_string(), "image".to_string());
-    params_to_sign.insert("timestamp".to_string(), timestamp.clone());
-    if let Some(folder_name) = option.folder {
-        params_to_sign.insert("folder".to_string(), folder_name);
+/// Talks to the Cloudinary upload API over one shared connection pool.
+pub struct CloudinaryClient {
+    cloud_env: CloudinaryEnv,
+    client: Client,
+}
+
+impl CloudinaryClient {
+    pub fn new(cloud_env: CloudinaryEnv) -> Result<Self> {
+        let client = Client::builder()
+            .connect_timeout(CONNECT_TIMEOUT)
+            .timeout(Duration::from_secs(cloud_env.timeout))
+            .build()?;
+
+        Ok(Self { cloud_env, client })
     }
-    if let Some(public_id) = option.public_id {
-        params_to_sign.insert("public_id".to_string(), public_id);
+
+    pub async fn upload(
+        &self,
+        base64_image: Base64Image,
+        option: UploadImageOptions,
+    ) -> Result<UploadedImage, CloudinaryError> {
+        let file = base64_image.into_inner();
+
+        let uploaded: UploadResult = self
+            .post("upload", upload_params(option), Some(file))
+            .await?;
+
+        Ok(UploadedImage::new(
+            uploaded.secure_url.unwrap_or(uploaded.url),
+            uploaded.public_id,
+        ))
     }
-    if let Some(transformation) = option.transformation {
-        params_to_sign.insert("transformation".to_string(), transformation);
+
+    /// Deletes an uploaded image and invalidates its CDN copies. Images that
+    /// are already gone count as deleted.
+    pub async fn destroy(&self, public_id: &str) -> Result<(), CloudinaryError> {
+        let mut params = BTreeMap::new();
+        params.insert("public_id".to_string(), public_id.to_string());
+        params.insert("invalidate".to_string(), "true".to_string());
+
+        let destroyed: DestroyResult = self.post("destroy", params, None).await?;
+        match destroyed.result.as_str() {
+            "ok" | "not found" => Ok(()),
+            _ => Err(CloudinaryError::NotDestroyed {
+                public_id: public_id.to_string(),
+                result: destroyed.result,
+            }),
+        }
     }
 
-    let mut sorted_keys: Vec<_> = params_to_sign.keys().collect();
-    sorted_keys.sort();
-    for (i, key) in sorted_keys.iter().enumerate() {
-        if let Some(value) = &params_to_sign.get(*key) {
-            let key_str = key.to_string();
-            let value_str = value.to_string();
-            if key_str != "resource_type" {
-                let key_value = &format!("{}={}", key_str, value_str);
-                hasher.update(key_value);
-                if i < sorted_keys.len() - 1 {
-                    hasher.update("&");
-                }
+    /// Posts a signed form to `{base_url}/v1_1/{cloud_name}/image/{action}`,
+    /// retrying with exponential backoff while the failure is retryable.
+    async fn post<T: DeserializeOwned>(
+        &self,
+        action: &str,
+        params: BTreeMap<String, String>,
+        file: Option<String>,
+    ) -> Result<T, CloudinaryError> {
+        let url = format!(
+            "{}/v1_1/{}/image/{}",
+            self.cloud_env.base_url.trim_end_matches('/'),
+            self.cloud_env.cloud_name,
+            action
+        );
+
+        let mut attempt = 0;
+        loop {
+            // Signed again on every attempt, the timestamp must stay fresh.
+            let mut form = form_builder(params.clone(), Utc::now().timestamp(), &self.cloud_env);
+            if let Some(file) = &file {
+                form = form.part("file", Part::text(file.clone()));
+            }
+
+            let result = match self.client.post(&url).multipart(form).send().await {
+                Ok(response) => decode(response).await,
+                Err(e) => Err(CloudinaryError::from(e)),
             };
 
-            form = form.text(key_str, value_str);
+            match result {
+                Err(e) if e.is_retryable() && attempt < self.cloud_env.max_retries => {
+                    let delay = RETRY_BASE_DELAY * 2u32.pow(attempt);
+                    attempt += 1;
+                    warn!(
+                        "Cloudinary {} failed, retry {} of {} in {:?}: {}",
+                        action, attempt, self.cloud_env.max_retries, delay, e
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                result => return result,
+            }
         }
     }
-    hasher.update(cloud_env.api_secret.clone());
+}
+
+async fn decode<T: DeserializeOwned>(response: reqwest::Response) -> Result<T, CloudinaryError> {
+    let status = response.status();
+    let body = response.text().await?;
+
+    if status.is_success() {
+        return serde_json::from_str(&body)
+            .map_err(|_| CloudinaryError::UnexpectedResponse { status, body });
+    }
 
-    form = form.text("signature", format!("{:x}", hasher.finalize()));
-    form = form.text("api_key", cloud_env.api_key.clone());
-    form = form.text("timestamp", timestamp.clone());
+    match serde_json::from_str::<ErrorPayload>(&body) {
+        Ok(payload) => Err(CloudinaryError::Api {
+            status,
+            message: payload.error.message,
+        }),
+        Err(_) => Err(CloudinaryError::UnexpectedResponse { status, body }),
+    }
+}
 
-    Ok(form)
+fn upload_params(option: UploadImageOptions) -> BTreeMap<String, String> {
+    let mut params = BTreeMap::new();
+    if let Some(folder_name) = option.folder {
+        params.insert("folder".to_string(), folder_name);
+    }
+    if let Some(public_id) = option.public_id {
+        params.insert("public_id".to_string(), public_id);
+    }
+    if let Some(transformation) = option.transformation {
+        params.insert("transformation".to_string(), transformation);
+    }
+
+    params
+}
+
+/// Signature of a request with `params` sent at `timestamp` (Unix seconds):
+/// SHA-1 of the `key=value` pairs and the timestamp sorted by key, joined with
+/// `&` and followed by the API secret.
+pub fn signature(params: &BTreeMap<String, String>, timestamp: i64, api_secret: &str) -> String {
+    let mut params_to_sign = params.clone();
+    params_to_sign.insert("timestamp".to_string(), timestamp.to_string());
+
+    let string_to_sign = params_to_sign
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect::<Vec<_>>()
+        .join("&");
+    let mut hasher = Sha1::new();
+    hasher.update(string_to_sign);
+    hasher.update(api_secret);
+
+    format!("{:x}", hasher.finalize())
 }
 
-pub async fn upload(
-    base64_image: Base64Image,
-    option: UploadImageOptions,
-) -> Result<UploadedImage> {
-    let cloud_env = get_cloudinary_env()?;
-
-    let file = Part::text(base64_image.into_inner());
-    let form = form_builder(option, &cloud_env)?;
-    let multipart = form.part("file", file);
-    let client = reqwest::Client::new();
-    let url = format!(
-        "https://api.cloudinary.com/v1_1/{}/image/upload",
-        cloud_env.cloud_name
-    );
-
-    let response = client
-        .post(&url)
-        .multipart(multipart)
-        .send()
-        .await
-        .context(format!("upload to {}", url))?;
-
-    let text = response.text().await?;
-    let json: UploadedImage =
-        serde_json::from_str(&text).context(format!("failed to parse:\n\n {}", text))?;
-    Ok(json)
+/// A form with `params`, the timestamp and the API key, signed with the API
+/// secret.
+fn form_builder(
+    mut params: BTreeMap<String, String>,
+    timestamp: i64,
+    cloud_env: &CloudinaryEnv,
+) -> Form {
+    let signature = signature(&params, timestamp, &cloud_env.api_secret);
+    params.insert("timestamp".to_string(), timestamp.to_string());
+
+    let mut form = Form::new();
+    for (key, value) in params {
+        form = form.text(key, value);
+    }
+
+    form.text("signature", signature)
+        .text("api_key", cloud_env.api_key.clone())
 }
\ No newline at end of file
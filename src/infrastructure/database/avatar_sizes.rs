@@ -0,0 +1,37 @@
+use std::io::Write;
+
+use diesel::{
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::{Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+    sql_types::Jsonb,
+};
+
+use crate::domain::value_objects::uploaded_image::AvatarSizes;
+
+/// Carries the Diesel derives for [`AvatarSizes`], which is stored as a JSONB
+/// array of its serde form.
+#[allow(dead_code)]
+#[derive(AsExpression, FromSqlRow)]
+#[diesel(foreign_derive)]
+#[diesel(sql_type = Jsonb)]
+struct AvatarSizesProxy(AvatarSizes);
+
+impl ToSql<Jsonb, Pg> for AvatarSizes {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        // JSONB version byte.
+        out.write_all(&[1])?;
+        serde_json::to_writer(out, self)?;
+
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<Jsonb, Pg> for AvatarSizes {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let value = <serde_json::Value as FromSql<Jsonb, Pg>>::from_sql(bytes)?;
+
+        Ok(serde_json::from_value(value)?)
+    }
+}
@@ -1,6 +0,0 @@
--- This file should undo anything in `up.sql`
-DROP TABLE IF EXISTS crew_memberships;
-
-DROP TABLE IF EXISTS missions;
-
-DROP TABLE IF EXISTS brawlers;
\ No newline at end of file
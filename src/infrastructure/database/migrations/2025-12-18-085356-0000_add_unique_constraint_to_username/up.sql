@@ -1,3 +0,0 @@
--- Your SQL goes here
-ALTER TABLE brawlers
-ADD CONSTRAINT unique_username UNIQUE (username);
\ No newline at end of file
@@ -1,3 +1,3 @@
--- This file should undo anything in `up.sql`
+-- Your SQL goes here
 ALTER TABLE brawlers
 ADD CONSTRAINT unique_username UNIQUE (username);
\ No newline at end of file
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE brawlers
+DROP COLUMN sessions_revoked_at;
+
+DROP TABLE IF EXISTS revoked_tokens;
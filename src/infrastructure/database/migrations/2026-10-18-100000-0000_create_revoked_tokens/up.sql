@@ -0,0 +1,15 @@
+-- Your SQL goes here
+CREATE TABLE revoked_tokens (
+    jti UUID PRIMARY KEY,
+    brawler_id INTEGER NOT NULL,
+    expires_at TIMESTAMP NOT NULL,
+    revoked_at TIMESTAMP NOT NULL DEFAULT now()
+);
+
+ALTER TABLE
+    revoked_tokens
+ADD
+    CONSTRAINT fk_revoked_token_brawler FOREIGN KEY (brawler_id) REFERENCES brawlers(id) ON DELETE CASCADE;
+
+ALTER TABLE brawlers
+ADD COLUMN sessions_revoked_at TIMESTAMP;
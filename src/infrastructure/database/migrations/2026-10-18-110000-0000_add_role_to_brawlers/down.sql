@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE brawlers
+DROP CONSTRAINT check_brawler_role,
+DROP COLUMN "role";
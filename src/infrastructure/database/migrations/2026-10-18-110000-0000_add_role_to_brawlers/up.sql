@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE brawlers
+ADD COLUMN "role" VARCHAR(32) NOT NULL DEFAULT 'brawler',
+ADD CONSTRAINT check_brawler_role CHECK ("role" IN ('brawler', 'moderator', 'admin'));
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE missions
+DROP CONSTRAINT check_mission_crew_bounds,
+DROP COLUMN max_crew,
+DROP COLUMN min_crew;
@@ -0,0 +1,20 @@
+-- Your SQL goes here
+ALTER TABLE missions
+ADD COLUMN min_crew INTEGER NOT NULL DEFAULT 1,
+ADD COLUMN max_crew INTEGER;
+
+-- Existing missions get the server's crew limit, `squad.max_crew_per_mission` as set by
+-- `run_migrations` (10 without it), or their current crew if that is already larger.
+UPDATE missions
+SET max_crew = GREATEST(
+    COALESCE(NULLIF(current_setting('squad.max_crew_per_mission', true), '')::INTEGER, 10),
+    (
+        SELECT count(*)
+        FROM crew_memberships
+        WHERE crew_memberships.mission_id = missions.id
+    )::INTEGER
+);
+
+ALTER TABLE missions
+ALTER COLUMN max_crew SET NOT NULL,
+ADD CONSTRAINT check_mission_crew_bounds CHECK (min_crew >= 1 AND min_crew <= max_crew);
@@ -0,0 +1,19 @@
+-- Your SQL goes here
+CREATE TABLE mission_status_history (
+    id SERIAL PRIMARY KEY,
+    mission_id INTEGER NOT NULL,
+    from_status VARCHAR(32) NOT NULL,
+    to_status VARCHAR(32) NOT NULL,
+    actor_id INTEGER NOT NULL,
+    reason TEXT,
+    created_at TIMESTAMP NOT NULL
+);
+
+ALTER TABLE
+    mission_status_history
+ADD
+    CONSTRAINT fk_status_history_mission FOREIGN KEY (mission_id) REFERENCES missions(id) ON DELETE CASCADE,
+ADD
+    CONSTRAINT fk_status_history_actor FOREIGN KEY (actor_id) REFERENCES brawlers(id);
+
+CREATE INDEX idx_mission_status_history_mission_id ON mission_status_history (mission_id, created_at);
@@ -0,0 +1,9 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE mission_status_history
+ALTER COLUMN from_status TYPE VARCHAR(32) USING from_status::text,
+ALTER COLUMN to_status TYPE VARCHAR(32) USING to_status::text;
+
+ALTER TABLE missions
+ALTER COLUMN "status" TYPE VARCHAR(255) USING "status"::text;
+
+DROP TYPE mission_status;
@@ -0,0 +1,38 @@
+-- Your SQL goes here
+CREATE TYPE mission_status AS ENUM ('Open', 'InProgress', 'Completed', 'Failed', 'Cancelled');
+
+-- Refuse to guess: a status that is not a spelling variant of a known one stops the
+-- migration so that an operator can look at it before anything is rewritten.
+DO $$
+DECLARE
+    unknown TEXT;
+BEGIN
+    SELECT string_agg(DISTINCT quote_literal("status"), ', ') INTO unknown
+    FROM missions
+    WHERE lower(replace(replace("status", '_', ''), ' ', '')) NOT IN (
+        'open', 'inprogress', 'completed', 'failed', 'cancelled', 'canceled'
+    );
+
+    IF unknown IS NOT NULL THEN
+        RAISE EXCEPTION 'missions.status has values that map to no mission_status: %', unknown;
+    END IF;
+END
+$$;
+
+-- Bring spelling variants back in line.
+UPDATE missions
+SET "status" = CASE lower(replace(replace("status", '_', ''), ' ', ''))
+    WHEN 'open' THEN 'Open'
+    WHEN 'inprogress' THEN 'InProgress'
+    WHEN 'completed' THEN 'Completed'
+    WHEN 'failed' THEN 'Failed'
+    WHEN 'cancelled' THEN 'Cancelled'
+    WHEN 'canceled' THEN 'Cancelled'
+END;
+
+ALTER TABLE missions
+ALTER COLUMN "status" TYPE mission_status USING "status"::mission_status;
+
+ALTER TABLE mission_status_history
+ALTER COLUMN from_status TYPE mission_status USING from_status::mission_status,
+ALTER COLUMN to_status TYPE mission_status USING to_status::mission_status;
@@ -1,3 +1,3 @@
--- This file should undo anything in `up.sql`
-ALTER TABLE brawlers
-DROP CONSTRAINT unique_username UNIQUE (username);
\ No newline at end of file
+-- This file should undo anything in `up.sql`
+ALTER TABLE brawlers
+DROP COLUMN avatar_sizes;
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+CREATE TABLE pending_image_deletions (
+    public_id VARCHAR(255) PRIMARY KEY,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    queued_at TIMESTAMP NOT NULL
+);
@@ -0,0 +1,37 @@
+use std::{io::Write, str::FromStr};
+
+use diesel::{
+    deserialize::{self, FromSql, FromSqlRow},
+    expression::AsExpression,
+    pg::{Pg, PgValue},
+    serialize::{self, IsNull, Output, ToSql},
+};
+
+use crate::{
+    domain::value_objects::mission_statuses::MissionStatuses,
+    infrastructure::database::schema::sql_types::MissionStatus,
+};
+
+/// Carries the Diesel derives for [`MissionStatuses`], which is stored as the
+/// `mission_status` Postgres enum whose labels match its `Display`.
+#[allow(dead_code)]
+#[derive(AsExpression, FromSqlRow)]
+#[diesel(foreign_derive)]
+#[diesel(sql_type = MissionStatus)]
+struct MissionStatusesProxy(MissionStatuses);
+
+impl ToSql<MissionStatus, Pg> for MissionStatuses {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self.to_string().as_bytes())?;
+
+        Ok(IsNull::No)
+    }
+}
+
+impl FromSql<MissionStatus, Pg> for MissionStatuses {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let status = std::str::from_utf8(bytes.as_bytes())?;
+
+        Ok(MissionStatuses::from_str(status)?)
+    }
+}
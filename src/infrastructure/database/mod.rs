@@ -1,3 +1,5 @@
 pub mod repositories;
 pub mod postgresql_connection;
 pub mod schema;
+mod avatar_sizes;
+mod mission_status;
@@ -1,13 +1,60 @@
+use std::{sync::Arc, time::Duration};
+
 use anyhow::Result;
 use diesel::{
     prelude::*,
     r2d2::{ConnectionManager, Pool},
+    sql_query,
+    sql_types::Text,
 };
+use diesel_migrations::{EmbeddedMigrations, MigrationHarness, embed_migrations};
+
+use crate::config::config_model::Database;
 
 pub type PgPoolSquad = Pool<ConnectionManager<PgConnection>>;
 
-pub fn establish_connection(database_url: &str) -> Result<PgPoolSquad> {
-    let manager = ConnectionManager::<PgConnection>::new(database_url);
-    let pool = Pool::builder().build(manager)?;
+pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("src/infrastructure/database/migrations");
+
+pub fn establish_connection(database: &Database) -> Result<PgPoolSquad> {
+    let manager = ConnectionManager::<PgConnection>::new(&database.url);
+    let pool = Pool::builder()
+        .max_size(database.max_connections)
+        .min_idle(database.min_idle)
+        .connection_timeout(Duration::from_secs(database.connection_timeout))
+        .idle_timeout(database.idle_timeout.map(Duration::from_secs))
+        .build(manager)?;
     Ok(pool)
+}
+
+/// Applies the embedded migrations the database has not seen yet and returns
+/// their versions. `max_crew_per_mission` backfills the crew bound of missions
+/// created before there was one.
+pub fn run_migrations(db_pool: &PgPoolSquad, max_crew_per_mission: u32) -> Result<Vec<String>> {
+    let mut conn = db_pool.get()?;
+
+    sql_query("SELECT set_config('squad.max_crew_per_mission', $1, false)")
+        .bind::<Text, _>(max_crew_per_mission.to_string())
+        .execute(&mut conn)?;
+
+    let applied = conn
+        .run_pending_migrations(MIGRATIONS)
+        .map_err(|e| anyhow::anyhow!("Failed to run migrations: {}", e))?;
+
+    Ok(applied.iter().map(|version| version.to_string()).collect())
+}
+
+/// Runs Diesel work on Tokio's blocking pool so that checking out a connection
+/// and waiting on Postgres never stalls the async workers.
+pub async fn run_blocking<T, F>(db_pool: &Arc<PgPoolSquad>, f: F) -> Result<T>
+where
+    F: FnOnce(&mut PgConnection) -> Result<T> + Send + 'static,
+    T: Send + 'static,
+{
+    let db_pool = Arc::clone(db_pool);
+
+    tokio::task::spawn_blocking(move || {
+        let mut conn = db_pool.get()?;
+        f(&mut conn)
+    })
+    .await?
 }
\ No newline at end of file
@@ -1,19 +1,46 @@
 use anyhow::Result;
 use async_trait::async_trait;
 use diesel::{
-    ExpressionMethods, RunQueryDsl, SelectableHelper, insert_into,
+    ExpressionMethods, RunQueryDsl, SelectableHelper, insert_into, sql_query,
+    sql_types::{BigInt, Integer},
     query_dsl::methods::{FilterDsl, SelectDsl},
 };
 use std::sync::Arc;
 
 use crate::{
     domain::{
-        entities::brawlers::{BrawlerEntity, RegisterBrawlerEntity},
-        repositories::brawlers::BrawlerRepository, value_objects::{base64_image::Base64Image, uploaded_image::UploadedImage},
+        entities::brawlers::{BrawlerEntity, EditBrawlerEntity, RegisterBrawlerEntity},
+        repositories::brawlers::BrawlerRepository, value_objects::{brawler_model::BrawlerModel, leaderboard_filter::LeaderboardSort, roles::Role, uploaded_image::{AvatarSizes, UploadedImage}},
     },
-    infrastructure::{cloudinary::UploadImageOptions, database::{postgresql_connection::PgPoolSquad, schema::brawlers}},
+    infrastructure::database::{postgresql_connection::{PgPoolSquad, run_blocking}, schema::brawlers},
 };
 
+/// Per brawler statistics, crew counts only cover missions that were not removed.
+const BRAWLER_STATS_QUERY: &str = r#"
+    SELECT b.id,
+           b.display_name,
+           b.avatar_url,
+           b.avatar_sizes,
+           crew.mission_success_count,
+           crew.mission_joined_count,
+           crew.mission_failed_count,
+           led.mission_led_count
+    FROM brawlers b
+    CROSS JOIN LATERAL (
+        SELECT COUNT(*) FILTER (WHERE m.status = 'Completed') AS mission_success_count,
+               COUNT(*) AS mission_joined_count,
+               COUNT(*) FILTER (WHERE m.status = 'Failed') AS mission_failed_count
+        FROM crew_memberships cm
+        JOIN missions m ON m.id = cm.mission_id AND m.deleted_at IS NULL
+        WHERE cm.brawler_id = b.id
+    ) crew
+    CROSS JOIN LATERAL (
+        SELECT COUNT(*) AS mission_led_count
+        FROM missions m
+        WHERE m.chief_id = b.id AND m.deleted_at IS NULL
+    ) led
+"#;
+
 pub struct BrawlerPostgres {
     db_pool: Arc<PgPoolSquad>,
 }
@@ -27,45 +54,151 @@ impl BrawlerPostgres {
 #[async_trait]
 impl BrawlerRepository for BrawlerPostgres {
     async fn register(&self, register_brawler_entity: RegisterBrawlerEntity) -> Result<i32> {
-        let mut connection = Arc::clone(&self.db_pool).get()?;
+        run_blocking(&self.db_pool, move |connection| {
+            let result = insert_into(brawlers::table)
+                .values(&register_brawler_entity)
+                .returning(brawlers::id)
+                .get_result::<i32>(connection)?;
+
+            Ok(result)
+        })
+        .await
+    }
+
+    async fn find_by_username(&self, username: &str) -> Result<BrawlerEntity> {
+        let username = username.to_string();
+
+        run_blocking(&self.db_pool, move |connection| {
+            let result = brawlers::table
+                .filter(brawlers::username.eq(username))
+                .select(BrawlerEntity::as_select())
+                .first::<BrawlerEntity>(connection)?;
+
+            Ok(result)
+        })
+        .await
+    }
+
+    async fn find_by_id(&self, brawler_id: i32) -> Result<BrawlerEntity> {
+        run_blocking(&self.db_pool, move |connection| {
+            let result = brawlers::table
+                .filter(brawlers::id.eq(brawler_id))
+                .select(BrawlerEntity::as_select())
+                .first::<BrawlerEntity>(connection)?;
+
+            Ok(result)
+        })
+        .await
+    }
+
+    async fn find_profile(&self, brawler_id: i32) -> Result<BrawlerModel> {
+        run_blocking(&self.db_pool, move |connection| {
+            let result = sql_query(format!("{} WHERE b.id = $1", BRAWLER_STATS_QUERY))
+                .bind::<Integer, _>(brawler_id)
+                .get_result::<BrawlerModel>(connection)?;
+
+            Ok(result)
+        })
+        .await
+    }
+
+    async fn leaderboard(
+        &self,
+        sort_by: LeaderboardSort,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<BrawlerModel>> {
+        run_blocking(&self.db_pool, move |connection| {
+            let query = format!(
+                "{} ORDER BY {} DESC, b.id ASC LIMIT $1 OFFSET $2",
+                BRAWLER_STATS_QUERY,
+                sort_by.column()
+            );
+            let result = sql_query(query)
+                .bind::<BigInt, _>(limit)
+                .bind::<BigInt, _>(offset)
+                .load::<BrawlerModel>(connection)?;
 
-        let result = insert_into(brawlers::table)
-            .values(&register_brawler_entity)
-            .returning(brawlers::id)
-            .get_result::<i32>(&mut connection)?;
+            Ok(result)
+        })
+        .await
+    }
+
+    async fn edit(&self, brawler_id: i32, edit_brawler_entity: EditBrawlerEntity) -> Result<()> {
+        run_blocking(&self.db_pool, move |connection| {
+            diesel::update(brawlers::table)
+                .filter(brawlers::id.eq(brawler_id))
+                .set(&edit_brawler_entity)
+                .returning(brawlers::id)
+                .get_result::<i32>(connection)?;
 
-        Ok(result)
+            Ok(())
+        })
+        .await
     }
 
-    async fn find_by_username(&self, username: &String) -> Result<BrawlerEntity> {
-        let mut connection = Arc::clone(&self.db_pool).get()?;
+    async fn update_password(&self, brawler_id: i32, hashed_password: String) -> Result<()> {
+        run_blocking(&self.db_pool, move |connection| {
+            diesel::update(brawlers::table)
+                .filter(brawlers::id.eq(brawler_id))
+                .set(brawlers::password.eq(hashed_password))
+                .returning(brawlers::id)
+                .get_result::<i32>(connection)?;
+
+            Ok(())
+        })
+        .await
+    }
 
-        let result = brawlers::table
-            .filter(brawlers::username.eq(username))
-            .select(BrawlerEntity::as_select())
-            .first::<BrawlerEntity>(&mut connection)?;
+    async fn update_role(&self, brawler_id: i32, role: Role) -> Result<()> {
+        run_blocking(&self.db_pool, move |connection| {
+            diesel::update(brawlers::table)
+                .filter(brawlers::id.eq(brawler_id))
+                .set(brawlers::role.eq(role.to_string()))
+                .returning(brawlers::id)
+                .get_result::<i32>(connection)?;
 
-        Ok(result)
+            Ok(())
+        })
+        .await
     }
-    async fn upload_avatar(
+
+    async fn update_avatar(
         &self,
         brawler_id: i32,
-        base64_image: Base64Image,
-        option: UploadImageOptions,
-    ) -> Result<UploadedImage> {
-        let uploaded_image =    
-            crate::infrastructure::cloudinary::upload(base64_image, option).await?;
-
-        let mut conn = Arc::clone(&self.db_pool).get()?;
-
-        diesel::update(brawlers::table)
-            .filter(brawlers::id.eq(brawler_id))
-            .set((
-                brawlers::avatar_url.eq(uploaded_image.url.clone()),
-                brawlers::avatar_public_id.eq(uploaded_image.public_id.clone()),
-            ))
-            .execute(&mut conn)?;
-
-        Ok(uploaded_image)
+        uploaded_image: UploadedImage,
+        avatar_sizes: AvatarSizes,
+    ) -> Result<()> {
+        run_blocking(&self.db_pool, move |conn| {
+            diesel::update(brawlers::table)
+                .filter(brawlers::id.eq(brawler_id))
+                .set((
+                    brawlers::avatar_url.eq(uploaded_image.url),
+                    brawlers::avatar_public_id.eq(uploaded_image.public_id),
+                    brawlers::avatar_sizes.eq(avatar_sizes),
+                ))
+                .returning(brawlers::id)
+                .get_result::<i32>(conn)?;
+
+            Ok(())
+        })
+        .await
+    }
+
+    async fn remove_avatar(&self, brawler_id: i32) -> Result<()> {
+        run_blocking(&self.db_pool, move |conn| {
+            diesel::update(brawlers::table)
+                .filter(brawlers::id.eq(brawler_id))
+                .set((
+                    brawlers::avatar_url.eq(None::<String>),
+                    brawlers::avatar_public_id.eq(None::<String>),
+                    brawlers::avatar_sizes.eq(AvatarSizes::default()),
+                ))
+                .returning(brawlers::id)
+                .get_result::<i32>(conn)?;
+
+            Ok(())
+        })
+        .await
     }
 }
\ No newline at end of file
@@ -1,14 +1,13 @@
 use crate::{
     domain::{
-        entities::missions::{AddMissionEntity, EditMissionEntity},
+        entities::missions::AddMissionEntity,
         repositories::mission_management::MissionManagementRepository,
-        value_objects::mission_statuses::MissionStatuses,
     },
-    infrastructure::database::{postgresql_connection::PgPoolSquad, schema::missions},
+    infrastructure::database::{postgresql_connection::{PgPoolSquad, run_blocking}, schema::missions},
 };
 use anyhow::{Ok, Result};
 use async_trait::async_trait;
-use diesel::{ExpressionMethods, RunQueryDsl, dsl::now, dsl::update, insert_into};
+use diesel::{ExpressionMethods, RunQueryDsl, dsl::update, insert_into};
 use std::sync::Arc;
 
 pub struct MissionManagementPostgres {
@@ -24,39 +23,27 @@ impl MissionManagementPostgres {
 #[async_trait]
 impl MissionManagementRepository for MissionManagementPostgres {
     async fn add(&self, add_mission_entity: AddMissionEntity) -> Result<i32> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
-        let result = insert_into(missions::table)
-            .values(add_mission_entity)
-            .returning(missions::id)
-            .get_result::<i32>(&mut conn)?;
-        Ok(result)
+        run_blocking(&self.db_pool, move |conn| {
+            let result = insert_into(missions::table)
+                .values(add_mission_entity)
+                .returning(missions::id)
+                .get_result::<i32>(conn)?;
+            Ok(result)
+        })
+        .await
     }
 
-    async fn edit(&self, mission_id: i32, edit_mission_entity: EditMissionEntity) -> Result<i32> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
-        let result = update(missions::table)
-            .filter(missions::id.eq(mission_id))
-            .filter(missions::deleted_at.is_null())
-            .filter(missions::status.eq(MissionStatuses::Open.to_string()))
-            .set(edit_mission_entity)
-            .returning(missions::id)
-            .get_result::<i32>(&mut conn)?;
-        Ok(result)
-    }
-
-    async fn remove(&self, mission_id: i32, chief_id: i32) -> Result<()> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
-
-        update(missions::table)
-            .filter(missions::id.eq(mission_id))
-            .filter(missions::deleted_at.is_null())
-            .filter(missions::status.eq(MissionStatuses::Open.to_string()))
-            .set((
-                missions::deleted_at.eq(now),
-                missions::chief_id.eq(chief_id),
-            ))
-            .execute(&mut conn)?;
+    async fn restore(&self, mission_id: i32) -> Result<i32> {
+        run_blocking(&self.db_pool, move |conn| {
+            let result = update(missions::table)
+                .filter(missions::id.eq(mission_id))
+                .filter(missions::deleted_at.is_not_null())
+                .set(missions::deleted_at.eq(None::<chrono::NaiveDateTime>))
+                .returning(missions::id)
+                .get_result::<i32>(conn)?;
 
-        Ok(())
+            Ok(result)
+        })
+        .await
     }
 }
\ No newline at end of file
@@ -2,12 +2,15 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use diesel::{
     ExpressionMethods,
     PgTextExpressionMethods,
     QueryDsl,
     RunQueryDsl,
     SelectableHelper,
+    dsl::sql,
+    sql_types::{BigInt, Bool, Integer, Timestamp, Varchar},
 };
 
 use crate::{
@@ -15,15 +18,22 @@ use crate::{
         entities::{
             missions::MissionEntity,
             brawler_view::BrawlerViewEntity,
+            mission_status_history::MissionStatusHistoryEntity,
         },
         repositories::mission_viewing::MissionViewingRepository,
-        value_objects::mission_filter::MissionFilter,
+        value_objects::{
+            mission_filter::{MissionKeyset, MissionQuery, MissionSort, SortOrder},
+            uploaded_image::AvatarSizes,
+        },
     },
     infrastructure::database::{
-        postgresql_connection::PgPoolSquad,
-        schema::{crew_memberships, missions},
+        postgresql_connection::{PgPoolSquad, run_blocking},
+        schema::{brawlers, crew_memberships, mission_status_history, missions},
     },
 };
+const CREW_COUNT_SUBQUERY: &str =
+    "(SELECT COUNT(*) FROM crew_memberships WHERE crew_memberships.mission_id = missions.id)";
+
 pub struct MissionViewingPostgres {
     db_pool: Arc<PgPoolSquad>,
 }
@@ -37,59 +47,254 @@ impl MissionViewingPostgres {
 #[async_trait]
 impl MissionViewingRepository for MissionViewingPostgres {
     async fn crew_counting(&self, mission_id: i32) -> Result<i64> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
-
-        let value = crew_memberships::table
-            .filter(crew_memberships::mission_id.eq(mission_id))
-            .count()
-            .first::<i64>(&mut conn)?;
+        run_blocking(&self.db_pool, move |conn| {
+            let count = crew_memberships::table
+                .filter(crew_memberships::mission_id.eq(mission_id))
+                .count()
+                .first::<i64>(conn)?;
 
-        let count = i64::try_from(value)?;
-        Ok(count)
+            Ok(count)
+        })
+        .await
     }
 
     async fn get_one(&self, mission_id: i32) -> Result<MissionEntity> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
-        let result = missions::table
-            .filter(missions::id.eq(mission_id))
-            .filter(missions::deleted_at.is_null())
-            .select(MissionEntity::as_select())
-            .first::<MissionEntity>(&mut conn)?;
-
-        Ok(result)
+        run_blocking(&self.db_pool, move |conn| {
+            let result = missions::table
+                .filter(missions::id.eq(mission_id))
+                .filter(missions::deleted_at.is_null())
+                .select(MissionEntity::as_select())
+                .first::<MissionEntity>(conn)?;
+
+            Ok(result)
+        })
+        .await
     }
 
-    async fn get_all(&self, mission_filter: &MissionFilter) -> Result<Vec<MissionEntity>> {
-        let mut conn = Arc::clone(&self.db_pool).get()?;
+    async fn get_with_crew_counts(
+        &self,
+        mission_query: &MissionQuery,
+    ) -> Result<Vec<(MissionEntity, i64)>> {
+        let mission_query = mission_query.clone();
+
+        run_blocking(&self.db_pool, move |conn| {
+            let mut query = missions::table
+                .filter(missions::deleted_at.is_null())
+                .into_boxed();
 
-        let mut query = missions::table
-            .filter(missions::deleted_at.is_null())
-            .into_boxed();
+            if let Some(mission_id) = mission_query.mission_id {
+                query = query.filter(missions::id.eq(mission_id));
+            };
+            if !mission_query.statuses.is_empty() {
+                query = query.filter(missions::status.eq_any(mission_query.statuses));
+            };
+            if let Some(name) = &mission_query.name {
+                query = query.filter(missions::name.ilike(contains_pattern(name)));
+            };
+            if let Some(chief_id) = mission_query.chief_id {
+                query = query.filter(missions::chief_id.eq(chief_id));
+            };
+            if let Some(brawler_id) = mission_query.joined_by {
+                query = query.filter(
+                    missions::id.eq_any(
+                        crew_memberships::table
+                            .filter(crew_memberships::brawler_id.eq(brawler_id))
+                            .select(crew_memberships::mission_id),
+                    ),
+                );
+            };
+            if let Some(brawler_id) = mission_query.not_joined_by {
+                query = query.filter(
+                    missions::id.ne_all(
+                        crew_memberships::table
+                            .filter(crew_memberships::brawler_id.eq(brawler_id))
+                            .select(crew_memberships::mission_id),
+                    ),
+                );
+            };
+            if let Some(has_free_slots) = mission_query.has_free_slots {
+                let comparison = if has_free_slots {
+                    " < missions.max_crew"
+                } else {
+                    " >= missions.max_crew"
+                };
+                query = query.filter(sql::<Bool>(CREW_COUNT_SUBQUERY).sql(comparison));
+            };
+            if let Some(created_after) = mission_query.created_after {
+                query = query.filter(missions::created_at.gt(created_after));
+            };
+            if let Some(created_before) = mission_query.created_before {
+                query = query.filter(missions::created_at.lt(created_before));
+            };
 
-        if let Some(status) = &mission_filter.status {
-            let status_string = status.to_string();
-            query = query.filter(missions::status.eq(status_string));
-        };
-        if let Some(name) = &mission_filter.name {
-            query = query.filter(missions::name.ilike(format!("%{}%", name)));
-        };
+            if let Some(keyset) = &mission_query.after {
+                let column = match mission_query.sort_by {
+                    MissionSort::CreatedAt => "missions.created_at",
+                    MissionSort::UpdatedAt => "missions.updated_at",
+                    MissionSort::Name => "missions.name",
+                };
+                let comparison = match mission_query.order {
+                    SortOrder::Asc => ">",
+                    SortOrder::Desc => "<",
+                };
+                let row = format!("({}, missions.id) {} (", column, comparison);
+
+                query = match keyset {
+                    MissionKeyset::Timestamp(value, id) => query.filter(
+                        sql::<Bool>(&row)
+                            .bind::<Timestamp, _>(*value)
+                            .sql(", ")
+                            .bind::<Integer, _>(*id)
+                            .sql(")"),
+                    ),
+                    MissionKeyset::Name(value, id) => query.filter(
+                        sql::<Bool>(&row)
+                            .bind::<Varchar, _>(value.clone())
+                            .sql(", ")
+                            .bind::<Integer, _>(*id)
+                            .sql(")"),
+                    ),
+                };
+            };
+
+            query = match (mission_query.sort_by, mission_query.order) {
+                (MissionSort::CreatedAt, SortOrder::Asc) => {
+                    query.order_by((missions::created_at.asc(), missions::id.asc()))
+                }
+                (MissionSort::CreatedAt, SortOrder::Desc) => {
+                    query.order_by((missions::created_at.desc(), missions::id.desc()))
+                }
+                (MissionSort::UpdatedAt, SortOrder::Asc) => {
+                    query.order_by((missions::updated_at.asc(), missions::id.asc()))
+                }
+                (MissionSort::UpdatedAt, SortOrder::Desc) => {
+                    query.order_by((missions::updated_at.desc(), missions::id.desc()))
+                }
+                (MissionSort::Name, SortOrder::Asc) => {
+                    query.order_by((missions::name.asc(), missions::id.asc()))
+                }
+                (MissionSort::Name, SortOrder::Desc) => {
+                    query.order_by((missions::name.desc(), missions::id.desc()))
+                }
+            };
+
+            let value = query
+                .select((
+                    MissionEntity::as_select(),
+                    sql::<BigInt>(CREW_COUNT_SUBQUERY),
+                ))
+                .limit(mission_query.limit)
+                .load::<(MissionEntity, i64)>(conn)?;
+
+            Ok(value)
+        })
+        .await
+    }
 
-        let value = query
-            .select(MissionEntity::as_select())
-            .order_by(missions::created_at.desc())
-            .load::<MissionEntity>(&mut conn)?;
+    async fn joined_mission_ids(&self, brawler_id: i32) -> Result<Vec<i32>> {
+        run_blocking(&self.db_pool, move |conn| {
+            let mission_ids = crew_memberships::table
+                .filter(crew_memberships::brawler_id.eq(brawler_id))
+                .select(crew_memberships::mission_id)
+                .load::<i32>(conn)?;
 
-        Ok(value)
+            Ok(mission_ids)
+        })
+        .await
     }
 
-    async fn get_mission_brawlers(
-    &self,
-    _mission_id: i32,
-) -> Result<Vec<BrawlerViewEntity>> {
-    Ok(vec![])
+    async fn get_mission_brawlers(&self, mission_id: i32) -> Result<Vec<BrawlerViewEntity>> {
+        run_blocking(&self.db_pool, move |conn| {
+            let (chief_id, display_name, avatar_url, avatar_sizes, created_at) = missions::table
+                .inner_join(brawlers::table)
+                .filter(missions::id.eq(mission_id))
+                .filter(missions::deleted_at.is_null())
+                .select((
+                    brawlers::id,
+                    brawlers::display_name,
+                    brawlers::avatar_url,
+                    brawlers::avatar_sizes,
+                    missions::created_at,
+                ))
+                .first::<(i32, String, Option<String>, AvatarSizes, NaiveDateTime)>(conn)?;
+
+            let crew = crew_memberships::table
+                .inner_join(brawlers::table)
+                .filter(crew_memberships::mission_id.eq(mission_id))
+                .select((
+                    brawlers::id,
+                    brawlers::display_name,
+                    brawlers::avatar_url,
+                    brawlers::avatar_sizes,
+                    crew_memberships::joined_at,
+                ))
+                .order_by((crew_memberships::joined_at.asc(), brawlers::id.asc()))
+                .load::<(i32, String, Option<String>, AvatarSizes, NaiveDateTime)>(conn)?;
+
+            let chief = BrawlerViewEntity {
+                id: chief_id,
+                display_name,
+                avatar_url,
+                avatar_sizes,
+                joined_at: created_at,
+                is_chief: true,
+            };
+            let result = std::iter::once(chief)
+                .chain(crew.into_iter().map(
+                    |(id, display_name, avatar_url, avatar_sizes, joined_at)| BrawlerViewEntity {
+                        id,
+                        display_name,
+                        avatar_url,
+                        avatar_sizes,
+                        joined_at,
+                        is_chief: false,
+                    },
+                ))
+                .collect();
+
+            Ok(result)
+        })
+        .await
+    }
+
+    async fn get_status_history(
+        &self,
+        mission_id: i32,
+    ) -> Result<Vec<MissionStatusHistoryEntity>> {
+        run_blocking(&self.db_pool, move |conn| {
+            missions::table
+                .filter(missions::id.eq(mission_id))
+                .filter(missions::deleted_at.is_null())
+                .select(missions::id)
+                .first::<i32>(conn)?;
+
+            let result = mission_status_history::table
+                .filter(mission_status_history::mission_id.eq(mission_id))
+                .order_by((
+                    mission_status_history::created_at.asc(),
+                    mission_status_history::id.asc(),
+                ))
+                .select(MissionStatusHistoryEntity::as_select())
+                .load::<MissionStatusHistoryEntity>(conn)?;
+
+            Ok(result)
+        })
+        .await
+    }
 }
 
+/// `ILIKE` pattern for `name` anywhere in the column, with the `%`, `_` and `\`
+/// inside `name` escaped so they match themselves.
+fn contains_pattern(name: &str) -> String {
+    let mut pattern = String::with_capacity(name.len() + 2);
+    pattern.push('%');
+    for c in name.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            pattern.push('\\');
+        }
+        pattern.push(c);
+    }
+    pattern.push('%');
 
-    
-    
+    pattern
 }
\ No newline at end of file
@@ -1,5 +1,7 @@
 pub mod brawlers;
-pub mod crew_operation;
 pub mod mission_management;
-pub mod mission_operation;
 pub mod mission_viewing;
+pub mod pending_image_deletions;
+pub mod refresh_tokens;
+pub mod token_revocation;
+pub mod transaction_provider;
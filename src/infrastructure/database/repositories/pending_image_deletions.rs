@@ -0,0 +1,95 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::Utc;
+use diesel::{ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper, delete, insert_into, update};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::pending_image_deletions::{
+            AddPendingImageDeletionEntity, PendingImageDeletionEntity,
+        },
+        repositories::pending_image_deletions::PendingImageDeletionRepository,
+    },
+    infrastructure::database::{
+        postgresql_connection::{PgPoolSquad, run_blocking},
+        schema::pending_image_deletions,
+    },
+};
+
+pub struct PendingImageDeletionPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl PendingImageDeletionPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl PendingImageDeletionRepository for PendingImageDeletionPostgres {
+    async fn add(&self, public_ids: Vec<String>) -> Result<()> {
+        let queued_at = Utc::now().naive_utc();
+        let rows = public_ids
+            .into_iter()
+            .map(|public_id| AddPendingImageDeletionEntity {
+                public_id,
+                queued_at,
+            })
+            .collect::<Vec<_>>();
+
+        run_blocking(&self.db_pool, move |conn| {
+            insert_into(pending_image_deletions::table)
+                .values(rows)
+                .on_conflict_do_nothing()
+                .execute(conn)?;
+
+            Ok(())
+        })
+        .await
+    }
+
+    async fn pending(&self) -> Result<Vec<PendingImageDeletionEntity>> {
+        run_blocking(&self.db_pool, move |conn| {
+            let result = pending_image_deletions::table
+                .order_by((
+                    pending_image_deletions::queued_at.asc(),
+                    pending_image_deletions::public_id.asc(),
+                ))
+                .select(PendingImageDeletionEntity::as_select())
+                .load::<PendingImageDeletionEntity>(conn)?;
+
+            Ok(result)
+        })
+        .await
+    }
+
+    async fn record_failure(&self, public_id: &str) -> Result<i32> {
+        let public_id = public_id.to_string();
+
+        run_blocking(&self.db_pool, move |conn| {
+            let attempts = update(pending_image_deletions::table)
+                .filter(pending_image_deletions::public_id.eq(public_id))
+                .set(pending_image_deletions::attempts.eq(pending_image_deletions::attempts + 1))
+                .returning(pending_image_deletions::attempts)
+                .get_result::<i32>(conn)?;
+
+            Ok(attempts)
+        })
+        .await
+    }
+
+    async fn remove(&self, public_id: &str) -> Result<()> {
+        let public_id = public_id.to_string();
+
+        run_blocking(&self.db_pool, move |conn| {
+            delete(pending_image_deletions::table)
+                .filter(pending_image_deletions::public_id.eq(public_id))
+                .execute(conn)?;
+
+            Ok(())
+        })
+        .await
+    }
+}
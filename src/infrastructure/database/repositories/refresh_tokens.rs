@@ -0,0 +1,116 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::Utc;
+use diesel::{
+    Connection, ExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper, insert_into, update,
+};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::refresh_tokens::{AddRefreshTokenEntity, RefreshTokenEntity},
+        repositories::refresh_tokens::RefreshTokenRepository,
+    },
+    infrastructure::database::{postgresql_connection::{PgPoolSquad, run_blocking}, schema::refresh_tokens},
+};
+
+pub struct RefreshTokenPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl RefreshTokenPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl RefreshTokenRepository for RefreshTokenPostgres {
+    async fn add(&self, add_refresh_token_entity: AddRefreshTokenEntity) -> Result<()> {
+        run_blocking(&self.db_pool, move |conn| {
+            insert_into(refresh_tokens::table)
+                .values(add_refresh_token_entity)
+                .execute(conn)?;
+
+            Ok(())
+        })
+        .await
+    }
+
+    async fn find_by_hash(&self, token_hash: &str) -> Result<RefreshTokenEntity> {
+        let token_hash = token_hash.to_string();
+
+        run_blocking(&self.db_pool, move |conn| {
+            let result = refresh_tokens::table
+                .filter(refresh_tokens::token_hash.eq(token_hash))
+                .select(RefreshTokenEntity::as_select())
+                .first::<RefreshTokenEntity>(conn)?;
+
+            Ok(result)
+        })
+        .await
+    }
+
+    async fn rotate(
+        &self,
+        used_token_id: i32,
+        add_refresh_token_entity: AddRefreshTokenEntity,
+    ) -> Result<bool> {
+        let now = Utc::now().naive_utc();
+
+        run_blocking(&self.db_pool, move |conn| {
+            let rotated = conn.transaction::<bool, anyhow::Error, _>(|conn| {
+                let updated = update(refresh_tokens::table)
+                    .filter(refresh_tokens::id.eq(used_token_id))
+                    .filter(refresh_tokens::used_at.is_null())
+                    .filter(refresh_tokens::revoked_at.is_null())
+                    .set(refresh_tokens::used_at.eq(now))
+                    .execute(conn)?;
+
+                if updated == 0 {
+                    return Ok(false);
+                }
+
+                insert_into(refresh_tokens::table)
+                    .values(add_refresh_token_entity)
+                    .execute(conn)?;
+
+                Ok(true)
+            })?;
+
+            Ok(rotated)
+        })
+        .await
+    }
+
+    async fn revoke_family(&self, family_id: Uuid) -> Result<()> {
+        let now = Utc::now().naive_utc();
+
+        run_blocking(&self.db_pool, move |conn| {
+            update(refresh_tokens::table)
+                .filter(refresh_tokens::family_id.eq(family_id))
+                .filter(refresh_tokens::revoked_at.is_null())
+                .set(refresh_tokens::revoked_at.eq(now))
+                .execute(conn)?;
+
+            Ok(())
+        })
+        .await
+    }
+
+    async fn revoke_all(&self, brawler_id: i32) -> Result<()> {
+        let now = Utc::now().naive_utc();
+
+        run_blocking(&self.db_pool, move |conn| {
+            update(refresh_tokens::table)
+                .filter(refresh_tokens::brawler_id.eq(brawler_id))
+                .filter(refresh_tokens::revoked_at.is_null())
+                .set(refresh_tokens::revoked_at.eq(now))
+                .execute(conn)?;
+
+            Ok(())
+        })
+        .await
+    }
+}
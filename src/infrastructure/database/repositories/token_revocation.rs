@@ -0,0 +1,100 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, SubsecRound, Utc};
+use diesel::{
+    ExpressionMethods, NullableExpressionMethods, QueryDsl, RunQueryDsl, SelectableHelper, delete,
+    insert_into, update,
+};
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        entities::revoked_tokens::{AddRevokedTokenEntity, RevokedTokenEntity},
+        repositories::token_revocation::TokenRevocationRepository,
+    },
+    infrastructure::database::{
+        postgresql_connection::{PgPoolSquad, run_blocking},
+        schema::{brawlers, revoked_tokens},
+    },
+};
+
+pub struct TokenRevocationPostgres {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl TokenRevocationPostgres {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl TokenRevocationRepository for TokenRevocationPostgres {
+    async fn revoke(&self, add_revoked_token_entity: AddRevokedTokenEntity) -> Result<()> {
+        run_blocking(&self.db_pool, move |conn| {
+            insert_into(revoked_tokens::table)
+                .values(add_revoked_token_entity)
+                .on_conflict_do_nothing()
+                .execute(conn)?;
+
+            Ok(())
+        })
+        .await
+    }
+
+    async fn revoke_all(&self, brawler_id: i32) -> Result<NaiveDateTime> {
+        // Timestamps are UTC and taken here rather than with SQL `now()`, which
+        // depends on the session time zone. Microseconds are all Postgres keeps.
+        let now = Utc::now().naive_utc().trunc_subsecs(6);
+
+        run_blocking(&self.db_pool, move |conn| {
+            let cutoff = update(brawlers::table)
+                .filter(brawlers::id.eq(brawler_id))
+                .set(brawlers::sessions_revoked_at.eq(now))
+                .returning(brawlers::sessions_revoked_at.assume_not_null())
+                .get_result::<NaiveDateTime>(conn)?;
+
+            Ok(cutoff)
+        })
+        .await
+    }
+
+    async fn active_revocations(&self) -> Result<Vec<RevokedTokenEntity>> {
+        let now = Utc::now().naive_utc();
+
+        run_blocking(&self.db_pool, move |conn| {
+            let result = revoked_tokens::table
+                .filter(revoked_tokens::expires_at.gt(now))
+                .select(RevokedTokenEntity::as_select())
+                .load::<RevokedTokenEntity>(conn)?;
+
+            Ok(result)
+        })
+        .await
+    }
+
+    async fn session_cutoffs(&self) -> Result<Vec<(i32, NaiveDateTime)>> {
+        run_blocking(&self.db_pool, move |conn| {
+            let result = brawlers::table
+                .filter(brawlers::sessions_revoked_at.is_not_null())
+                .select((brawlers::id, brawlers::sessions_revoked_at.assume_not_null()))
+                .load::<(i32, NaiveDateTime)>(conn)?;
+
+            Ok(result)
+        })
+        .await
+    }
+
+    async fn remove_expired(&self) -> Result<usize> {
+        let now = Utc::now().naive_utc();
+
+        run_blocking(&self.db_pool, move |conn| {
+            let removed = delete(revoked_tokens::table)
+                .filter(revoked_tokens::expires_at.le(now))
+                .execute(conn)?;
+
+            Ok(removed)
+        })
+        .await
+    }
+}
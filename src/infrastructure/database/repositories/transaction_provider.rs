@@ -0,0 +1,150 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::Utc;
+use diesel::{
+    Connection, ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl, SelectableHelper, delete,
+    insert_into, update,
+};
+
+use crate::{
+    domain::{
+        entities::{
+            crew_memberships::CrewMembershipEntity,
+            mission_status_history::AddMissionStatusHistoryEntity,
+            missions::{EditMissionEntity, MissionEntity},
+        },
+        errors::DomainResult,
+        repositories::transaction_provider::{MissionTransaction, TransactionProvider},
+        value_objects::mission_statuses::MissionStatuses,
+    },
+    infrastructure::database::{
+        postgresql_connection::{PgPoolSquad, run_blocking},
+        schema::{crew_memberships, mission_status_history, missions},
+    },
+};
+
+pub struct PostgresTransactionProvider {
+    db_pool: Arc<PgPoolSquad>,
+}
+
+impl PostgresTransactionProvider {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl TransactionProvider for PostgresTransactionProvider {
+    type Transaction = PgConnection;
+
+    async fn transaction<T, F>(&self, f: F) -> DomainResult<T>
+    where
+        F: FnOnce(&mut Self::Transaction) -> DomainResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let result = run_blocking(&self.db_pool, move |conn| {
+            conn.transaction::<T, anyhow::Error, _>(|conn| f(conn).map_err(anyhow::Error::from))
+        })
+        .await?;
+
+        Ok(result)
+    }
+}
+
+impl MissionTransaction for PgConnection {
+    fn lock_mission(&mut self, mission_id: i32) -> Result<MissionEntity> {
+        let result = missions::table
+            .filter(missions::id.eq(mission_id))
+            .filter(missions::deleted_at.is_null())
+            .select(MissionEntity::as_select())
+            .for_update()
+            .first::<MissionEntity>(self)?;
+
+        Ok(result)
+    }
+
+    fn crew_count(&mut self, mission_id: i32) -> Result<i64> {
+        let count = crew_memberships::table
+            .filter(crew_memberships::mission_id.eq(mission_id))
+            .count()
+            .get_result::<i64>(self)?;
+
+        Ok(count)
+    }
+
+    fn edit_mission(&mut self, mission_id: i32, edit_mission_entity: EditMissionEntity) -> Result<i32> {
+        let result = update(missions::table)
+            .filter(missions::id.eq(mission_id))
+            .filter(missions::deleted_at.is_null())
+            .filter(missions::status.eq(MissionStatuses::Open))
+            .set(edit_mission_entity)
+            .returning(missions::id)
+            .get_result::<i32>(self)?;
+
+        Ok(result)
+    }
+
+    fn remove_mission(&mut self, mission_id: i32, chief_id: i32) -> Result<()> {
+        let now = Utc::now().naive_utc();
+        let updated = update(missions::table)
+            .filter(missions::id.eq(mission_id))
+            .filter(missions::deleted_at.is_null())
+            .filter(missions::status.eq(MissionStatuses::Open))
+            .set((
+                missions::deleted_at.eq(now),
+                missions::updated_at.eq(now),
+                missions::chief_id.eq(chief_id),
+            ))
+            .execute(self)?;
+        if updated == 0 {
+            return Err(diesel::result::Error::NotFound.into());
+        }
+
+        Ok(())
+    }
+
+    fn join(&mut self, crew_membership_entity: CrewMembershipEntity) -> Result<()> {
+        insert_into(crew_memberships::table)
+            .values(crew_membership_entity)
+            .execute(self)?;
+
+        Ok(())
+    }
+
+    fn leave(&mut self, crew_membership_entity: CrewMembershipEntity) -> Result<()> {
+        let deleted = delete(crew_memberships::table)
+            .filter(crew_memberships::brawler_id.eq(crew_membership_entity.brawler_id))
+            .filter(crew_memberships::mission_id.eq(crew_membership_entity.mission_id))
+            .execute(self)?;
+        if deleted == 0 {
+            return Err(diesel::result::Error::NotFound.into());
+        }
+
+        Ok(())
+    }
+
+    fn change_status(&mut self, transition: AddMissionStatusHistoryEntity) -> Result<()> {
+        // The mission and its history row share one UTC timestamp, so they
+        // sort together whatever the session time zone.
+        let now = Utc::now().naive_utc();
+        let updated = update(missions::table)
+            .filter(missions::id.eq(transition.mission_id))
+            .filter(missions::status.eq(transition.from_status))
+            .set((
+                missions::status.eq(transition.to_status),
+                missions::updated_at.eq(now),
+            ))
+            .execute(self)?;
+        if updated == 0 {
+            return Err(diesel::result::Error::NotFound.into());
+        }
+
+        insert_into(mission_status_history::table)
+            .values((transition, mission_status_history::created_at.eq(now)))
+            .execute(self)?;
+
+        Ok(())
+    }
+}
@@ -1,5 +1,11 @@
 // @generated automatically by Diesel CLI.
 
+pub mod sql_types {
+    #[derive(diesel::query_builder::QueryId, Clone, diesel::sql_types::SqlType)]
+    #[diesel(postgres_type(name = "mission_status"))]
+    pub struct MissionStatus;
+}
+
 diesel::table! {
     brawlers (id) {
         id -> Int4,
@@ -15,6 +21,10 @@ diesel::table! {
         avatar_url -> Nullable<Varchar>,
         #[max_length = 255]
         avatar_public_id -> Nullable<Varchar>,
+        sessions_revoked_at -> Nullable<Timestamp>,
+        #[max_length = 32]
+        role -> Varchar,
+        avatar_sizes -> Jsonb,
     }
 }
 
@@ -27,22 +37,85 @@ diesel::table! {
 }
 
 diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::MissionStatus;
+
+    mission_status_history (id) {
+        id -> Int4,
+        mission_id -> Int4,
+        from_status -> MissionStatus,
+        to_status -> MissionStatus,
+        actor_id -> Int4,
+        reason -> Nullable<Text>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use super::sql_types::MissionStatus;
+
     missions (id) {
         id -> Int4,
         #[max_length = 255]
         name -> Varchar,
         description -> Nullable<Text>,
-        #[max_length = 255]
-        status -> Varchar,
+        status -> MissionStatus,
         chief_id -> Int4,
         created_at -> Timestamp,
         updated_at -> Timestamp,
         deleted_at -> Nullable<Timestamp>,
+        min_crew -> Int4,
+        max_crew -> Int4,
+    }
+}
+
+diesel::table! {
+    pending_image_deletions (public_id) {
+        #[max_length = 255]
+        public_id -> Varchar,
+        attempts -> Int4,
+        queued_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    refresh_tokens (id) {
+        id -> Int4,
+        brawler_id -> Int4,
+        family_id -> Uuid,
+        #[max_length = 64]
+        token_hash -> Varchar,
+        expires_at -> Timestamp,
+        used_at -> Nullable<Timestamp>,
+        revoked_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    revoked_tokens (jti) {
+        jti -> Uuid,
+        brawler_id -> Int4,
+        expires_at -> Timestamp,
+        revoked_at -> Timestamp,
     }
 }
 
 diesel::joinable!(crew_memberships -> brawlers (brawler_id));
 diesel::joinable!(crew_memberships -> missions (mission_id));
+diesel::joinable!(mission_status_history -> brawlers (actor_id));
+diesel::joinable!(mission_status_history -> missions (mission_id));
 diesel::joinable!(missions -> brawlers (chief_id));
+diesel::joinable!(refresh_tokens -> brawlers (brawler_id));
+diesel::joinable!(revoked_tokens -> brawlers (brawler_id));
 
-diesel::allow_tables_to_appear_in_same_query!(brawlers, crew_memberships, missions,);
+diesel::allow_tables_to_appear_in_same_query!(
+    brawlers,
+    crew_memberships,
+    mission_status_history,
+    missions,
+    pending_image_deletions,
+    refresh_tokens,
+    revoked_tokens,
+);
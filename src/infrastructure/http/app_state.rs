@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::{
+    application::use_cases::{
+        authentication::AuthenticationUseCase, brawlers::BrawlersUseCase,
+        crew_operation::CrewOperationUseCase, mission_management::MissionManagementUseCase,
+        mission_operation::MissionOperationUseCase, mission_viewing::MissionViewingUseCase,
+    },
+    config::config_model::DotEnvyConfig,
+    domain::repositories::image_storage::ImageStorage,
+    infrastructure::{
+        http::repositories::Repositories,
+        image_storage::cleanup_queue::ImageCleanupQueue,
+        jwt::{jwt_authority::JwtAuthority, revocation_list::RevocationList},
+    },
+};
+
+/// Everything the routers need, assembled once at startup. Each router hands
+/// the use cases it serves to its handlers as state.
+pub struct AppState<R: Repositories> {
+    pub config: Arc<DotEnvyConfig>,
+    pub repositories: R,
+    pub image_storage: Arc<dyn ImageStorage + Send + Sync>,
+    pub image_cleanup: Arc<ImageCleanupQueue>,
+    pub jwt_authority: Arc<JwtAuthority>,
+    pub revocation_list: Arc<RevocationList>,
+    pub authentication: Arc<AuthenticationUseCase<R::Brawler, R::RefreshToken>>,
+    pub brawlers: Arc<BrawlersUseCase<R::Brawler, R::RefreshToken>>,
+    pub mission_management: Arc<MissionManagementUseCase<R::MissionManagement, R::Transaction>>,
+    pub mission_viewing: Arc<MissionViewingUseCase<R::MissionViewing>>,
+    pub mission_operation: Arc<MissionOperationUseCase<R::Transaction>>,
+    pub crew_operation: Arc<CrewOperationUseCase<R::Transaction>>,
+}
+
+impl<R: Repositories> AppState<R> {
+    /// Loads the signing keys and the current token revocations. Keeping the
+    /// revocation list in sync and retrying image deletions is left to the
+    /// caller, see [`RevocationList::spawn_sync`] and [`ImageCleanupQueue::spawn_worker`].
+    pub async fn new(
+        config: Arc<DotEnvyConfig>,
+        repositories: R,
+        image_storage: Arc<dyn ImageStorage + Send + Sync>,
+    ) -> Result<Self> {
+        let jwt_authority = Arc::new(JwtAuthority::from_env(&config.jwt)?);
+        let image_cleanup = Arc::new(ImageCleanupQueue::new(
+            Arc::clone(&image_storage),
+            repositories.pending_image_deletion(),
+        ));
+        let revocation_list =
+            Arc::new(RevocationList::load(repositories.token_revocation()).await?);
+
+        let authentication = AuthenticationUseCase::new(
+            repositories.brawler(),
+            repositories.refresh_token(),
+            Arc::clone(&revocation_list),
+            Arc::clone(&jwt_authority),
+        );
+        let brawlers = BrawlersUseCase::new(
+            repositories.brawler(),
+            repositories.refresh_token(),
+            Arc::clone(&image_storage),
+            Arc::clone(&image_cleanup),
+            Arc::clone(&revocation_list),
+            Arc::clone(&jwt_authority),
+            config.max_avatar_size_kb * 1024,
+        );
+        let mission_management = MissionManagementUseCase::new(
+            repositories.mission_management(),
+            repositories.transaction_provider(),
+            config.max_crew_per_mission,
+        );
+        let mission_viewing = MissionViewingUseCase::new(repositories.mission_viewing());
+        let mission_operation = MissionOperationUseCase::new(repositories.transaction_provider());
+        let crew_operation = CrewOperationUseCase::new(repositories.transaction_provider());
+
+        Ok(Self {
+            config,
+            repositories,
+            image_storage,
+            image_cleanup,
+            jwt_authority,
+            revocation_list,
+            authentication: Arc::new(authentication),
+            brawlers: Arc::new(brawlers),
+            mission_management: Arc::new(mission_management),
+            mission_viewing: Arc::new(mission_viewing),
+            mission_operation: Arc::new(mission_operation),
+            crew_operation: Arc::new(crew_operation),
+        })
+    }
+}
@@ -0,0 +1,57 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use tracing::error;
+
+use crate::domain::errors::DomainError;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorBody {
+    pub code: String,
+    pub message: String,
+}
+
+impl DomainError {
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            DomainError::NotFound(_) => StatusCode::NOT_FOUND,
+            DomainError::Forbidden(_) => StatusCode::FORBIDDEN,
+            DomainError::Conflict(_) | DomainError::InvalidTransition(_) => StatusCode::CONFLICT,
+            DomainError::Validation(_) => StatusCode::UNPROCESSABLE_ENTITY,
+            DomainError::PayloadTooLarge(_) => StatusCode::PAYLOAD_TOO_LARGE,
+            DomainError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            DomainError::Upstream(_) => StatusCode::BAD_GATEWAY,
+            DomainError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for DomainError {
+    fn into_response(self) -> Response {
+        let status = self.status_code();
+
+        // Internal and upstream details stay in the logs, clients only get a
+        // generic message.
+        let message = match &self {
+            DomainError::Internal(e) => {
+                error!("Internal error: {:?}", e);
+                "Internal server error".to_string()
+            }
+            DomainError::Upstream(e) => {
+                error!("Upstream error: {}", e);
+                "An upstream service failed".to_string()
+            }
+            _ => self.to_string(),
+        };
+
+        let body = ErrorBody {
+            code: self.code().to_string(),
+            message,
+        };
+
+        (status, Json(body)).into_response()
+    }
+}
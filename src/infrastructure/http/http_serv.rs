@@ -2,7 +2,7 @@ use std::{net::SocketAddr, sync::Arc, time::Duration};
 
 use anyhow::Result;
 use axum::{
-    Router, http::{
+    Extension, Router, http::{
         Method, StatusCode,
         header::{AUTHORIZATION, CONTENT_TYPE},
     },
@@ -19,33 +19,55 @@ use tower_http::{
 use tracing::info;
 
 use crate::{
-    config::config_model::DotEnvyConfig, infrastructure::{database::postgresql_connection::PgPoolSquad, http::routers::{self}}
+    config::config_model::{DotEnvyConfig, ImageStorageEnv}, infrastructure::{database::postgresql_connection::PgPoolSquad, image_storage, http::{app_state::AppState, repositories::{PostgresRepositories, Repositories}, routers::{self}}}
 };
 
-fn static_serve() -> Router {
+fn static_serve(image_storage_env: &ImageStorageEnv) -> Router {
     let dir = "statics";
 
     let service = ServeDir::new(dir).not_found_service(ServeFile::new(format!("{dir}/index.html")));
 
-    Router::new().fallback_service(service)
+    let router = Router::new().fallback_service(service);
+
+    // Uploaded images are served from their own directory, which need not live under `statics`.
+    match image_storage_env {
+        ImageStorageEnv::Local(local_env) if local_env.base_url.starts_with('/') && local_env.base_url != "/" => {
+            router.nest_service(local_env.base_url.trim_end_matches('/'), ServeDir::new(&local_env.dir))
+        }
+        _ => router,
+    }
 }
 
-fn api_serve(db_pool: Arc<PgPoolSquad>) -> Router {
+/// The `/api` router, also what the integration tests drive with in-memory repositories.
+pub fn api_serve<R: Repositories>(state: &AppState<R>) -> Router {
     Router::new()
-        .nest("/brawler", routers::brawlers::routes(Arc::clone(&db_pool)))
-        .nest("/auth", routers::authentication::routes(Arc::clone(&db_pool)))
-        .nest("/mission-management", routers::missions_management::routes(Arc::clone(&db_pool)))
-        .nest("/crew", routers::craw_operations::routes(Arc::clone(&db_pool)))
-        .nest("/mission", routers::missions_operations::routes(Arc::clone(&db_pool)))
-        .nest("/view", routers::missions_viewing::routes(Arc::clone(&db_pool)))
+        .nest("/brawler", routers::brawlers::routes(state))
+        .nest("/auth", routers::authentication::routes(state))
+        .nest("/mission-management", routers::missions_management::routes(state))
+        .nest("/crew", routers::craw_operations::routes(state))
+        .nest("/mission", routers::missions_operations::routes(state))
+        .nest("/view", routers::missions_viewing::routes(state))
+        .nest("/admin", routers::admin::routes(state))
     .fallback(|| async { (StatusCode::NOT_FOUND, "API not found") })
+    .layer(Extension(Arc::clone(&state.revocation_list)))
+    .layer(Extension(Arc::clone(&state.jwt_authority)))
 
 }
 
 pub async fn start(config: Arc<DotEnvyConfig>, db_pool: Arc<PgPoolSquad>) -> Result<()> {
+    let state = AppState::new(
+        Arc::clone(&config),
+        PostgresRepositories::new(db_pool),
+        image_storage::from_config(&config.image_storage)?,
+    )
+    .await?;
+    state.revocation_list.spawn_sync();
+    state.image_cleanup.spawn_worker();
+
     let app = Router::new()
-        .merge(static_serve())
-        .nest("/api", api_serve(db_pool))
+        .merge(static_serve(&config.image_storage))
+        .nest("/api", api_serve(&state))
+        .nest("/.well-known", routers::well_known::routes(Arc::clone(&state.jwt_authority)))
         .route("/health_check", get(routers::default_routers::health_check))
         // .fallback(default_router::health_check)
         // .route("/health_check", get(default_router::health_check)
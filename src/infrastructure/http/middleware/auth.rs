@@ -1,33 +1,112 @@
-use crate::infrastructure;
-use crate::config::config_loader::get_user_secret as get_user_secret_env;
-use axum::{http::{Request, StatusCode, header}, middleware::Next, body::Body, response::Response};
-use anyhow::Result;
-
-pub async fn authorization (mut req: Request<Body>, next: Next) -> Result<Response, StatusCode> {
-    let auth_header = req
-        .headers()
-        .get(header::AUTHORIZATION)
-        .and_then(|value| value.to_str().ok())
-        .ok_or(StatusCode::UNAUTHORIZED)?;
+use std::sync::Arc;
 
+use crate::{
+    domain::{errors::DomainError, value_objects::roles::Role},
+    infrastructure::jwt::{jwt_authority::JwtAuthority, jwt_model::Claims, revocation_list::RevocationList},
+};
+use axum::{
+    body::Body,
+    extract::{FromRequestParts, State},
+    http::{Request, header, request::Parts},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+/// The brawler behind a valid, unrevoked bearer token.
+///
+/// Handlers taking this extractor are protected on their own, a missing or bad
+/// token is answered with a 401 JSON body before the handler runs.
+#[derive(Debug, Clone)]
+pub struct AuthBrawler {
+    pub id: i32,
+    pub role: Role,
+    pub claims: Claims,
+}
+
+/// Like [`AuthBrawler`] for public routes: `None` without an `Authorization`
+/// header, while a token that is sent but invalid is still rejected.
+#[derive(Debug, Clone)]
+pub struct OptionalAuthBrawler(pub Option<AuthBrawler>);
+
+impl<S> FromRequestParts<S> for AuthBrawler
+where
+    S: Send + Sync,
+{
+    type Rejection = DomainError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let auth_header = parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .ok_or_else(|| DomainError::unauthorized("Missing bearer token!"))?;
+
+        authenticate(parts, auth_header.to_str().unwrap_or_default())
+    }
+}
+
+impl<S> FromRequestParts<S> for OptionalAuthBrawler
+where
+    S: Send + Sync,
+{
+    type Rejection = DomainError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let Some(auth_header) = parts.headers.get(header::AUTHORIZATION) else {
+            return Ok(Self(None));
+        };
+
+        let auth_brawler = authenticate(parts, auth_header.to_str().unwrap_or_default())?;
+
+        Ok(Self(Some(auth_brawler)))
+    }
+}
+
+fn authenticate(parts: &Parts, auth_header: &str) -> Result<AuthBrawler, DomainError> {
     let token = auth_header
         .strip_prefix("Bearer ")
-        .ok_or(StatusCode::UNAUTHORIZED)?;
+        .ok_or_else(|| DomainError::unauthorized("Missing bearer token!"))?;
 
-    let secret_env = get_user_secret_env().map_err(|_| StatusCode::UNAUTHORIZED)?;
+    let jwt_authority = parts
+        .extensions
+        .get::<Arc<JwtAuthority>>()
+        .ok_or_else(|| anyhow::anyhow!("JwtAuthority extension is missing"))?;
+
+    let claims = jwt_authority
+        .verify(token)
+        .map_err(|_| DomainError::unauthorized("Invalid or expired token!"))?;
+
+    let revocation_list = parts
+        .extensions
+        .get::<Arc<RevocationList>>()
+        .ok_or_else(|| anyhow::anyhow!("RevocationList extension is missing"))?;
+    if revocation_list.is_revoked(&claims) {
+        return Err(DomainError::unauthorized("Token has been revoked!"));
+    }
 
-    let claims = 
-        infrastructure::jwt::verify_token(secret_env, token.to_string())
-            .map_err(|_| StatusCode::UNAUTHORIZED)?;
-    
-    let brawler_id = claims
+    let id = claims
         .sub
         .parse::<i32>()
-        .map_err(|_| StatusCode::UNAUTHORIZED)?;
+        .map_err(|_| DomainError::unauthorized("Invalid or expired token!"))?;
 
-    req.extensions_mut().insert::<i32>(brawler_id);
+    Ok(AuthBrawler {
+        id,
+        role: claims.role,
+        claims,
+    })
+}
 
-    Ok(next.run(req).await)
-    
+/// Rejects brawlers below `required_role`:
+/// `.route_layer(from_fn_with_state(Role::Admin, require_role))`.
+pub async fn require_role(
+    State(required_role): State<Role>,
+    auth_brawler: AuthBrawler,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    if auth_brawler.role < required_role {
+        return DomainError::forbidden(format!("Requires the {} role!", required_role))
+            .into_response();
     }
 
+    next.run(req).await
+}
@@ -1,3 +1,7 @@
 pub mod routers;
 pub mod http_serv;
-pub mod middleware;
\ No newline at end of file
+pub mod middleware;
+pub mod error_response;
+pub mod extractors;
+pub mod repositories;
+pub mod app_state;
\ No newline at end of file
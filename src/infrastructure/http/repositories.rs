@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::repositories::{
+        brawlers::BrawlerRepository, mission_management::MissionManagementRepository,
+        mission_viewing::MissionViewingRepository,
+        pending_image_deletions::PendingImageDeletionRepository,
+        refresh_tokens::RefreshTokenRepository, token_revocation::TokenRevocationRepository,
+        transaction_provider::TransactionProvider,
+    },
+    infrastructure::database::{
+        postgresql_connection::PgPoolSquad,
+        repositories::{
+            brawlers::BrawlerPostgres, mission_management::MissionManagementPostgres,
+            mission_viewing::MissionViewingPostgres,
+            pending_image_deletions::PendingImageDeletionPostgres,
+            refresh_tokens::RefreshTokenPostgres, token_revocation::TokenRevocationPostgres,
+            transaction_provider::PostgresTransactionProvider,
+        },
+    },
+};
+
+/// The repository implementations the routers are built from: Postgres when
+/// serving, the in-memory fakes of the `testing` feature in tests.
+pub trait Repositories: Send + Sync + 'static {
+    type Brawler: BrawlerRepository + Send + Sync + 'static;
+    type RefreshToken: RefreshTokenRepository + Send + Sync + 'static;
+    type TokenRevocation: TokenRevocationRepository + Send + Sync + 'static;
+    type MissionManagement: MissionManagementRepository + Send + Sync + 'static;
+    type MissionViewing: MissionViewingRepository + Send + Sync + 'static;
+    type PendingImageDeletion: PendingImageDeletionRepository + Send + Sync + 'static;
+    type Transaction: TransactionProvider + Send + Sync + 'static;
+
+    fn brawler(&self) -> Arc<Self::Brawler>;
+    fn refresh_token(&self) -> Arc<Self::RefreshToken>;
+    fn token_revocation(&self) -> Arc<Self::TokenRevocation>;
+    fn mission_management(&self) -> Arc<Self::MissionManagement>;
+    fn mission_viewing(&self) -> Arc<Self::MissionViewing>;
+    fn pending_image_deletion(&self) -> Arc<Self::PendingImageDeletion>;
+    fn transaction_provider(&self) -> Arc<Self::Transaction>;
+}
+
+pub struct PostgresRepositories {
+    brawler: Arc<BrawlerPostgres>,
+    refresh_token: Arc<RefreshTokenPostgres>,
+    token_revocation: Arc<TokenRevocationPostgres>,
+    mission_management: Arc<MissionManagementPostgres>,
+    mission_viewing: Arc<MissionViewingPostgres>,
+    pending_image_deletion: Arc<PendingImageDeletionPostgres>,
+    transaction_provider: Arc<PostgresTransactionProvider>,
+}
+
+impl PostgresRepositories {
+    pub fn new(db_pool: Arc<PgPoolSquad>) -> Self {
+        Self {
+            brawler: Arc::new(BrawlerPostgres::new(Arc::clone(&db_pool))),
+            refresh_token: Arc::new(RefreshTokenPostgres::new(Arc::clone(&db_pool))),
+            token_revocation: Arc::new(TokenRevocationPostgres::new(Arc::clone(&db_pool))),
+            mission_management: Arc::new(MissionManagementPostgres::new(Arc::clone(&db_pool))),
+            mission_viewing: Arc::new(MissionViewingPostgres::new(Arc::clone(&db_pool))),
+            pending_image_deletion: Arc::new(PendingImageDeletionPostgres::new(Arc::clone(
+                &db_pool,
+            ))),
+            transaction_provider: Arc::new(PostgresTransactionProvider::new(db_pool)),
+        }
+    }
+}
+
+impl Repositories for PostgresRepositories {
+    type Brawler = BrawlerPostgres;
+    type RefreshToken = RefreshTokenPostgres;
+    type TokenRevocation = TokenRevocationPostgres;
+    type MissionManagement = MissionManagementPostgres;
+    type MissionViewing = MissionViewingPostgres;
+    type PendingImageDeletion = PendingImageDeletionPostgres;
+    type Transaction = PostgresTransactionProvider;
+
+    fn brawler(&self) -> Arc<Self::Brawler> {
+        Arc::clone(&self.brawler)
+    }
+
+    fn refresh_token(&self) -> Arc<Self::RefreshToken> {
+        Arc::clone(&self.refresh_token)
+    }
+
+    fn token_revocation(&self) -> Arc<Self::TokenRevocation> {
+        Arc::clone(&self.token_revocation)
+    }
+
+    fn mission_management(&self) -> Arc<Self::MissionManagement> {
+        Arc::clone(&self.mission_management)
+    }
+
+    fn mission_viewing(&self) -> Arc<Self::MissionViewing> {
+        Arc::clone(&self.mission_viewing)
+    }
+
+    fn pending_image_deletion(&self) -> Arc<Self::PendingImageDeletion> {
+        Arc::clone(&self.pending_image_deletion)
+    }
+
+    fn transaction_provider(&self) -> Arc<Self::Transaction> {
+        Arc::clone(&self.transaction_provider)
+    }
+}
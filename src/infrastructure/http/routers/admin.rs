@@ -0,0 +1,138 @@
+use std::sync::Arc;
+
+use axum::{
+    Router,
+    extract::State,
+    http::StatusCode,
+    middleware::from_fn_with_state,
+    response::IntoResponse,
+    routing::{delete, patch},
+};
+
+use crate::{
+    application::use_cases::{
+        brawlers::BrawlersUseCase, crew_operation::CrewOperationUseCase,
+        mission_management::MissionManagementUseCase, mission_operation::MissionOperationUseCase,
+    },
+    domain::{
+        repositories::{
+            brawlers::BrawlerRepository, mission_management::MissionManagementRepository,
+            refresh_tokens::RefreshTokenRepository,
+            transaction_provider::TransactionProvider,
+        },
+        value_objects::{
+            brawler_model::ChangeRoleModel, mission_status_history_model::ChangeStatusModel,
+            mission_statuses::MissionStatuses, roles::Role,
+        },
+    },
+    infrastructure::{
+        http::{
+            app_state::AppState,
+            extractors::{Json, Path},
+            middleware::auth::{AuthBrawler, require_role},
+            repositories::Repositories,
+        },
+    },
+};
+
+pub fn routes<R: Repositories>(state: &AppState<R>) -> Router {
+    let mission_operation_router = Router::new()
+        .route("/missions/{mission_id}/close", patch(force_close))
+        .with_state(Arc::clone(&state.mission_operation));
+    let mission_management_router = Router::new()
+        .route("/missions/{mission_id}/restore", patch(restore))
+        .with_state(Arc::clone(&state.mission_management));
+    let crew_operation_router = Router::new()
+        .route("/missions/{mission_id}/crew/{brawler_id}", delete(remove_member))
+        .with_state(Arc::clone(&state.crew_operation));
+    let brawlers_router = Router::new()
+        .route("/brawlers/{brawler_id}/role", patch(change_role))
+        .with_state(Arc::clone(&state.brawlers));
+
+    Router::new()
+        .merge(mission_operation_router)
+        .merge(mission_management_router)
+        .merge(crew_operation_router)
+        .merge(brawlers_router)
+        .route_layer(from_fn_with_state(Role::Admin, require_role))
+}
+
+pub async fn force_close<T>(
+    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T>>>,
+    AuthBrawler { id: admin_id, .. }: AuthBrawler,
+    Path(mission_id): Path<i32>,
+    change_status_model: Option<Json<ChangeStatusModel>>,
+) -> impl IntoResponse
+where
+    T: TransactionProvider + Send + Sync,
+{
+    let reason = change_status_model.and_then(|Json(model)| model.reason);
+
+    match mission_operation_use_case
+        .force_close(mission_id, admin_id, reason)
+        .await
+    {
+        Ok(mission_id) => (
+            StatusCode::OK,
+            format!("Mission({}) is now {:?}", mission_id, MissionStatuses::Failed),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn restore<T1, T2>(
+    State(mission_management_use_case): State<Arc<MissionManagementUseCase<T1, T2>>>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: MissionManagementRepository + Send + Sync,
+    T2: TransactionProvider + Send + Sync,
+{
+    match mission_management_use_case.restore(mission_id).await {
+        Ok(mission_id) => (
+            StatusCode::OK,
+            format!("Restore mission({}) successfully!!", mission_id),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn remove_member<T>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T>>>,
+    Path((mission_id, brawler_id)): Path<(i32, i32)>,
+) -> impl IntoResponse
+where
+    T: TransactionProvider + Send + Sync,
+{
+    match crew_operation_use_case
+        .remove_member(mission_id, brawler_id)
+        .await
+    {
+        Ok(_) => (
+            StatusCode::OK,
+            format!("Brawler({}) has been removed from Mission({})", brawler_id, mission_id),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn change_role<T1, T2>(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<T1, T2>>>,
+    Path(brawler_id): Path<i32>,
+    Json(change_role_model): Json<ChangeRoleModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+{
+    match brawlers_use_case
+        .change_role(brawler_id, change_role_model.role)
+        .await
+    {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
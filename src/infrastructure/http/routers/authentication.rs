@@ -1,38 +1,77 @@
 use std::sync::Arc;
 
-use axum::{Json, Router, extract::State, http::StatusCode, response::IntoResponse, routing::post};
+use axum::{Router, extract::State, http::StatusCode, response::IntoResponse, routing::post};
 
-use crate::{application::use_cases::authentication::AuthenticationUseCase, domain::repositories::brawlers::BrawlerRepository, infrastructure::{database::{postgresql_connection::PgPoolSquad, repositories::brawlers::BrawlerPostgres}, jwt::authentication_model::LoginModel}};
+use crate::{application::use_cases::authentication::AuthenticationUseCase, domain::repositories::{brawlers::BrawlerRepository, refresh_tokens::RefreshTokenRepository}, infrastructure::{http::{app_state::AppState, extractors::Json, middleware::auth::AuthBrawler, repositories::Repositories}, jwt::authentication_model::{LoginModel, LogoutModel, RefreshTokenModel}}};
 
 
 
-pub async fn login<T>(
-    State(authentication_use_case): State<Arc<AuthenticationUseCase<T>>>,
+pub async fn login<T1, T2>(
+    State(authentication_use_case): State<Arc<AuthenticationUseCase<T1, T2>>>,
     Json(login_model): Json<LoginModel>,
 ) -> impl IntoResponse
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
 {
     match authentication_use_case.login(login_model).await {
         Ok(passport) => {
             (StatusCode::OK, Json(passport)).into_response()
         }
-        Err(e) => {
-            (
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(e.to_string()),
-            )
-                .into_response()
-        }
+        Err(e) => e.into_response(),
     }
 }
 
+pub async fn refresh<T1, T2>(
+    State(authentication_use_case): State<Arc<AuthenticationUseCase<T1, T2>>>,
+    Json(refresh_token_model): Json<RefreshTokenModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+{
+    match authentication_use_case.refresh(refresh_token_model).await {
+        Ok(passport) => (StatusCode::OK, Json(passport)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn logout<T1, T2>(
+    State(authentication_use_case): State<Arc<AuthenticationUseCase<T1, T2>>>,
+    auth_brawler: AuthBrawler,
+    logout_model: Option<Json<LogoutModel>>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+{
+    let logout_model = logout_model.map(|Json(model)| model).unwrap_or_default();
+
+    match authentication_use_case.logout(auth_brawler.claims, logout_model).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let repository = BrawlerPostgres::new(db_pool);
-    let use_case = AuthenticationUseCase::new(Arc::new(repository));
+pub async fn logout_all<T1, T2>(
+    State(authentication_use_case): State<Arc<AuthenticationUseCase<T1, T2>>>,
+    AuthBrawler { id: brawler_id, .. }: AuthBrawler,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+{
+    match authentication_use_case.logout_all(brawler_id).await {
+        Ok(_) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
 
+pub fn routes<R: Repositories>(state: &AppState<R>) -> Router {
     Router::new()
         .route("/login", post(login))
-        .with_state(Arc::new(use_case))
+        .route("/refresh", post(refresh))
+        .route("/logout", post(logout))
+        .route("/logout-all", post(logout_all))
+        .with_state(Arc::clone(&state.authentication))
 }
\ No newline at end of file
@@ -1,59 +1,218 @@
 use std::sync::Arc;
 
-use axum::{ Extension, Json, Router, extract::State, http::StatusCode, response::IntoResponse, routing::post};
+use axum::{ Router, extract::{DefaultBodyLimit, Multipart, State}, http::StatusCode, response::IntoResponse, routing::{get, post}};
 
 use crate::{
     application::use_cases::brawlers::BrawlersUseCase,
     domain::{
-        repositories::brawlers::BrawlerRepository,
-        value_objects::{brawler_model::RegisterBrawlerModel, uploaded_image::UploadAvatar},
+        errors::{DomainError, DomainResult},
+        repositories::{brawlers::BrawlerRepository, refresh_tokens::RefreshTokenRepository},
+        value_objects::{base64_image::Base64Image, brawler_model::{ChangePasswordModel, EditBrawlerModel, RegisterBrawlerModel}, leaderboard_filter::LeaderboardFilter, uploaded_image::UploadAvatar},
     },
-    infrastructure::{database::{
-        postgresql_connection::PgPoolSquad, repositories::brawlers::BrawlerPostgres,
-    }, http::middleware::auth::authorization},
+    infrastructure::http::{app_state::AppState, extractors::{Json, Path, Query}, middleware::auth::AuthBrawler, repositories::Repositories},
 };
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let brawlers_repository = BrawlerPostgres::new(db_pool);
-    let brawlers_use_case = BrawlersUseCase::new(Arc::new(brawlers_repository));
-
-    let protected_router = Router::new()
-        .route("/avatar", post(upload_avatar))
-        .route_layer(axum::middleware::from_fn(authorization));
-
+pub fn routes<R: Repositories>(state: &AppState<R>) -> Router {
     Router::new()
-        .merge(protected_router)
         .route("/register", post(register))
-        .with_state(Arc::new(brawlers_use_case))
+        .route("/avatar", post(upload_avatar).delete(remove_avatar))
+        // The size cap is enforced while reading the file, see `read_avatar_file`.
+        .route("/avatar/file", post(upload_avatar_file).layer(DefaultBodyLimit::disable()))
+        .route("/me", get(get_me).patch(edit_me))
+        .route("/me/password", post(change_password))
+        .route("/leaderboard", get(leaderboard))
+        .route("/{brawler_id}", get(get_profile))
+        .with_state(Arc::clone(&state.brawlers))
 }
 
-pub async fn register<T>(
-    State(brawlers_use_case): State<Arc<BrawlersUseCase<T>>>,
+pub async fn register<T1, T2>(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<T1, T2>>>,
     Json(register_brawler_model): Json<RegisterBrawlerModel>,
 ) -> impl IntoResponse
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
 {
     match brawlers_use_case.register(register_brawler_model).await {
         Ok(passport) => (StatusCode::CREATED, Json(passport)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
 
-pub async fn upload_avatar<T>(
-    State(brawlers_use_case): State<Arc<BrawlersUseCase<T>>>,
-    Extension(brawler_id): Extension<i32>,
+pub async fn upload_avatar<T1, T2>(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<T1, T2>>>,
+    AuthBrawler { id: brawler_id, .. }: AuthBrawler,
     Json(upload_image): Json<UploadAvatar>,
 ) -> impl IntoResponse
 where
-    T: BrawlerRepository + Send + Sync,
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
   {
     match brawlers_use_case
         .upload_avatar(upload_image.base64_string, brawler_id)
         .await
     {
         Ok(uploaded_image) => (StatusCode::CREATED, Json(uploaded_image)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn remove_avatar<T1, T2>(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<T1, T2>>>,
+    AuthBrawler { id: brawler_id, .. }: AuthBrawler,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+{
+    match brawlers_use_case.remove_avatar(brawler_id).await {
+        Ok(()) => StatusCode::NO_CONTENT.into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+/// `multipart/form-data` with the image in an `avatar` file field.
+pub async fn upload_avatar_file<T1, T2>(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<T1, T2>>>,
+    AuthBrawler { id: brawler_id, .. }: AuthBrawler,
+    multipart: Multipart,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+{
+    let bytes = match read_avatar_file(&brawlers_use_case, multipart).await {
+        Ok(bytes) => bytes,
+        Err(e) => return e.into_response(),
+    };
+
+    match brawlers_use_case.upload_avatar_file(bytes, brawler_id).await {
+        Ok(uploaded_image) => (StatusCode::CREATED, Json(uploaded_image)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+/// Reads the `avatar` field chunk by chunk, so oversized or non-image uploads
+/// are turned away without waiting for the rest of the body.
+async fn read_avatar_file<T1, T2>(
+    brawlers_use_case: &BrawlersUseCase<T1, T2>,
+    mut multipart: Multipart,
+) -> DomainResult<Vec<u8>>
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+{
+    // Enough leading bytes for `infer` to tell PNG and JPEG apart.
+    const SNIFF_LEN: usize = 16;
+
+    let mut field = multipart
+        .next_field()
+        .await
+        .map_err(multipart_error)?
+        .ok_or_else(|| DomainError::validation("Missing `avatar` file field!"))?;
+    if field.name() != Some("avatar") {
+        return Err(DomainError::validation(format!(
+            "Unexpected form field {:?}, send the image as `avatar`!",
+            field.name().unwrap_or_default()
+        )));
+    }
+
+    let mut bytes = Vec::new();
+    let mut sniffed = false;
+    while let Some(chunk) = field.chunk().await.map_err(multipart_error)? {
+        brawlers_use_case.ensure_avatar_size(bytes.len() + chunk.len())?;
+        bytes.extend_from_slice(&chunk);
+
+        if !sniffed && bytes.len() >= SNIFF_LEN {
+            Base64Image::sniff_mime_type(&bytes)
+                .map_err(|e| DomainError::validation(e.to_string()))?;
+            sniffed = true;
+        }
+    }
+
+    Ok(bytes)
+}
+
+fn multipart_error(error: axum::extract::multipart::MultipartError) -> DomainError {
+    if error.status() == StatusCode::PAYLOAD_TOO_LARGE {
+        DomainError::payload_too_large(error.body_text())
+    } else {
+        DomainError::validation(error.body_text())
+    }
+}
+
+pub async fn get_me<T1, T2>(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<T1, T2>>>,
+    AuthBrawler { id: brawler_id, .. }: AuthBrawler,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+{
+    match brawlers_use_case.get_me(brawler_id).await {
+        Ok(profile) => (StatusCode::OK, Json(profile)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn edit_me<T1, T2>(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<T1, T2>>>,
+    AuthBrawler { id: brawler_id, .. }: AuthBrawler,
+    Json(edit_brawler_model): Json<EditBrawlerModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+{
+    match brawlers_use_case.edit_me(brawler_id, edit_brawler_model).await {
+        Ok(profile) => (StatusCode::OK, Json(profile)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn change_password<T1, T2>(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<T1, T2>>>,
+    AuthBrawler { id: brawler_id, .. }: AuthBrawler,
+    Json(change_password_model): Json<ChangePasswordModel>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+{
+    match brawlers_use_case
+        .change_password(brawler_id, change_password_model)
+        .await
+    {
+        Ok(passport) => (StatusCode::OK, Json(passport)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn get_profile<T1, T2>(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<T1, T2>>>,
+    Path(brawler_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+{
+    match brawlers_use_case.get_profile(brawler_id).await {
+        Ok(brawler_model) => (StatusCode::OK, Json(brawler_model)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn leaderboard<T1, T2>(
+    State(brawlers_use_case): State<Arc<BrawlersUseCase<T1, T2>>>,
+    filter: Query<LeaderboardFilter>,
+) -> impl IntoResponse
+where
+    T1: BrawlerRepository + Send + Sync,
+    T2: RefreshTokenRepository + Send + Sync,
+{
+    match brawlers_use_case.leaderboard(&filter).await {
+        Ok(leaderboard_model) => (StatusCode::OK, Json(leaderboard_model)).into_response(),
+        Err(e) => e.into_response(),
     }
 }
\ No newline at end of file
@@ -1,17 +1,16 @@
 use std::sync::Arc;
 
-use axum::{Extension, Router, extract::{Path, State}, http::StatusCode, response::IntoResponse, routing::{delete, post}};
+use axum::{Router, extract::State, http::StatusCode, response::IntoResponse, routing::{delete, post}};
 
-use crate::{application::use_cases::crew_operation::CrewOperationUseCase, domain::repositories::{crew_operation::CrewOperationRepository, mission_viewing::MissionViewingRepository}, infrastructure::{database::{postgresql_connection::PgPoolSquad, repositories::{crew_operation::CrewOperationPostgres, mission_viewing::MissionViewingPostgres}}, http::middleware::auth::authorization}};
+use crate::{application::use_cases::crew_operation::CrewOperationUseCase, domain::repositories::transaction_provider::TransactionProvider, infrastructure::http::{app_state::AppState, extractors::Path, middleware::auth::AuthBrawler, repositories::Repositories}};
 
-pub async fn join<T1, T2>(
-    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2>>>,
-    Extension(brawler_id): Extension<i32>,
+pub async fn join<T>(
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T>>>,
+    AuthBrawler { id: brawler_id, .. }: AuthBrawler,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
-    T1: CrewOperationRepository + Send + Sync + 'static,
-    T2: MissionViewingRepository + Send + Sync,
+    T: TransactionProvider + Send + Sync,
 {
     match crew_operation_use_case.join(mission_id, brawler_id).await {
         Ok(_) => (
@@ -19,18 +18,17 @@ where
             format!("Brawler({}) join Mission({})", brawler_id, mission_id),
         )
             .into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn leave<T1, T2>( 
-    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T1, T2>>>,
-    Extension(brawler_id): Extension<i32>,
+pub async fn leave<T>( 
+    State(crew_operation_use_case): State<Arc<CrewOperationUseCase<T>>>,
+    AuthBrawler { id: brawler_id, .. }: AuthBrawler,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
-    T1: CrewOperationRepository + Send + Sync + 'static,
-    T2: MissionViewingRepository + Send + Sync,
+    T: TransactionProvider + Send + Sync,
 {
 
     match crew_operation_use_case.leave(mission_id, brawler_id).await {
@@ -39,24 +37,15 @@ where
             format!("Brawler({}) has leaved Mission({})", brawler_id, mission_id),
         )
             .into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let crew_operation_repository = CrewOperationPostgres::new(Arc::clone(&db_pool));
-    let mission_viewing_repository = MissionViewingPostgres::new(Arc::clone(&db_pool));
-
-    let use_case = CrewOperationUseCase::new(
-        Arc::new(crew_operation_repository),
-        Arc::new(mission_viewing_repository),
-    );
-
+pub fn routes<R: Repositories>(state: &AppState<R>) -> Router {
     Router::new()
         .route("/join/{mission_id}", post(join))
         .route("/leave/{mission_id}", delete(leave))
-        .route_layer(axum::middleware::from_fn(authorization))
-        .with_state(Arc::new(use_case))
+        .with_state(Arc::clone(&state.crew_operation))
 }
 
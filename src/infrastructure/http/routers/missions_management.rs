@@ -1,20 +1,20 @@
 use std::sync::Arc;
 
-use axum::{Extension, Json, Router, extract::{Path, State}, http::StatusCode, response::IntoResponse, routing::{delete, patch, post}};
+use axum::{Router, extract::State, http::StatusCode, response::IntoResponse, routing::{delete, patch, post}};
 
-use crate::{application::use_cases::mission_management::MissionManagementUseCase, domain::{repositories::{mission_management::MissionManagementRepository, mission_viewing::MissionViewingRepository}, value_objects::mission_model::{AddMissionModel, EditMissionModel}}, infrastructure::{database::{postgresql_connection::PgPoolSquad, repositories::{mission_management::MissionManagementPostgres, mission_viewing::MissionViewingPostgres}}, http::middleware::auth::authorization}};
+use crate::{application::use_cases::mission_management::MissionManagementUseCase, domain::{repositories::{mission_management::MissionManagementRepository, transaction_provider::TransactionProvider}, value_objects::mission_model::{AddMissionModel, EditMissionModel}}, infrastructure::http::{app_state::AppState, extractors::{Json, Path}, middleware::auth::AuthBrawler, repositories::Repositories}};
 
 
 
 
 pub async fn add<T1, T2>(
     State(mission_management_use_case): State<Arc<MissionManagementUseCase<T1, T2>>>,
-    Extension(brawler_id): Extension<i32>,
+    AuthBrawler { id: brawler_id, .. }: AuthBrawler,
     Json(add_mission_model): Json<AddMissionModel>,
 ) -> impl IntoResponse
 where
     T1: MissionManagementRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
+    T2: TransactionProvider + Send + Sync,
 {
     match mission_management_use_case
         .add(brawler_id, add_mission_model)
@@ -26,19 +26,19 @@ where
             });
             (StatusCode::CREATED, axum::Json(json_value)).into_response()
         }
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
 pub async fn edit<T1, T2>(
     State(mission_management_use_case): State<Arc<MissionManagementUseCase<T1, T2>>>,
-    Extension(brawler_id): Extension<i32>,
+    AuthBrawler { id: brawler_id, .. }: AuthBrawler,
     Path(mission_id): Path<i32>,
     Json(edit_mission_model): Json<EditMissionModel>,
 ) -> impl IntoResponse
 where
     T1: MissionManagementRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
+    T2: TransactionProvider + Send + Sync,
 {
     match mission_management_use_case
         .edit(mission_id, brawler_id, edit_mission_model)
@@ -48,18 +48,18 @@ where
             let response = format!("Edit mission({}) successfully!!", mission_id);
             (StatusCode::OK, response).into_response()
         }
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
 pub async fn remove<T1, T2>(
     State(mission_management_use_case): State<Arc<MissionManagementUseCase<T1, T2>>>,
-    Extension(brawler_id): Extension<i32>,
+    AuthBrawler { id: brawler_id, .. }: AuthBrawler,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
     T1: MissionManagementRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
+    T2: TransactionProvider + Send + Sync,
 {
     match mission_management_use_case
         .remove(mission_id, brawler_id)
@@ -69,24 +69,15 @@ where
             let response = format!("Remove mission({}) successfully!!!", mission_id);
             (StatusCode::OK, response).into_response()
         }
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let mission_management_repository = MissionManagementPostgres::new(Arc::clone(&db_pool));
-    let mission_viewing_repository = MissionViewingPostgres::new(Arc::clone(&db_pool));
-
-    let mission_management_use_case = MissionManagementUseCase::new(
-        Arc::new(mission_management_repository),
-        Arc::new(mission_viewing_repository),
-    );
-
+pub fn routes<R: Repositories>(state: &AppState<R>) -> Router {
     Router::new()
         .route("/", post(add))
         .route("/{mission_id}", patch(edit))
         .route("/{mission_id}", delete(remove))
-        .route_layer(axum::middleware::from_fn(authorization))
-        // infrastructure::http::middleware::auth::authorization
-        .with_state(Arc::new(mission_management_use_case))
+        // infrastructure::http::middleware::auth::AuthBrawler
+        .with_state(Arc::clone(&state.mission_management))
 }
\ No newline at end of file
@@ -1,20 +1,22 @@
 use std::sync::Arc;
 
-use axum::{Extension, Router, extract::{Path, State}, http::StatusCode, middleware, response::IntoResponse, routing::patch};
+use axum::{Router, extract::State, http::StatusCode, response::IntoResponse, routing::patch};
 
-use crate::{application::use_cases::mission_operation::MissionOperationUseCase, domain::{repositories::{mission_operation::MissionOperationRepository, mission_viewing::MissionViewingRepository}, value_objects::mission_statuses::MissionStatuses}, infrastructure::{database::{postgresql_connection::PgPoolSquad, repositories::{mission_operation::MissionOperationPostgres, mission_viewing::MissionViewingPostgres}}, http::middleware::auth::authorization}};
+use crate::{application::use_cases::mission_operation::MissionOperationUseCase, domain::{repositories::transaction_provider::TransactionProvider, value_objects::{mission_status_history_model::ChangeStatusModel, mission_statuses::MissionStatuses}}, infrastructure::http::{app_state::AppState, extractors::{Json, Path}, middleware::auth::AuthBrawler, repositories::Repositories}};
 
-pub async fn in_progress<T1, T2>(
-    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T1, T2>>>,
-    Extension(chief_id): Extension<i32>,
+pub async fn in_progress<T>(
+    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T>>>,
+    AuthBrawler { id: chief_id, .. }: AuthBrawler,
     Path(mission_id): Path<i32>,
+    change_status_model: Option<Json<ChangeStatusModel>>,
 ) -> impl IntoResponse
 where
-    T1: MissionOperationRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
+    T: TransactionProvider + Send + Sync,
 {
+    let reason = change_status_model.and_then(|Json(model)| model.reason);
+
     match mission_operation_use_case
-        .in_progress(mission_id, chief_id)
+        .in_progress(mission_id, chief_id, reason)
         .await
     {
         Ok(mission_id) => (
@@ -22,21 +24,23 @@ where
             format!("Mission({}) is now {:?}" , mission_id, MissionStatuses::InProgress),
         )
             .into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn to_completed<T1, T2>(
-    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T1, T2>>>,
-    Extension(chief_id): Extension<i32>,
+pub async fn to_completed<T>(
+    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T>>>,
+    AuthBrawler { id: chief_id, .. }: AuthBrawler,
     Path(mission_id): Path<i32>,
+    change_status_model: Option<Json<ChangeStatusModel>>,
 ) -> impl IntoResponse
 where
-    T1: MissionOperationRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
+    T: TransactionProvider + Send + Sync,
 {
+    let reason = change_status_model.and_then(|Json(model)| model.reason);
+
     match mission_operation_use_case
-        .to_completed(mission_id, chief_id)
+        .to_completed(mission_id, chief_id, reason)
         .await
     {
         Ok(mission_id) => (
@@ -44,21 +48,23 @@ where
             format!("Mission({}) is now {:?}" , mission_id, MissionStatuses::Completed),
         )
             .into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn to_failed<T1, T2>(
-    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T1, T2>>>,
-    Extension(chief_id): Extension<i32>,
+pub async fn to_failed<T>(
+    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T>>>,
+    AuthBrawler { id: chief_id, .. }: AuthBrawler,
     Path(mission_id): Path<i32>,
+    change_status_model: Option<Json<ChangeStatusModel>>,
 ) -> impl IntoResponse
 where
-    T1: MissionOperationRepository + Send + Sync,
-    T2: MissionViewingRepository + Send + Sync,
+    T: TransactionProvider + Send + Sync,
 {
+    let reason = change_status_model.and_then(|Json(model)| model.reason);
+
     match mission_operation_use_case
-        .to_failed(mission_id, chief_id)
+        .to_failed(mission_id, chief_id, reason)
         .await
     {
         Ok(mission_id) => (
@@ -66,23 +72,64 @@ where
             format!("Mission({}) is now {:?}" , mission_id, MissionStatuses::Failed),
         )
             .into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn to_cancelled<T>(
+    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T>>>,
+    AuthBrawler { id: chief_id, .. }: AuthBrawler,
+    Path(mission_id): Path<i32>,
+    change_status_model: Option<Json<ChangeStatusModel>>,
+) -> impl IntoResponse
+where
+    T: TransactionProvider + Send + Sync,
+{
+    let reason = change_status_model.and_then(|Json(model)| model.reason);
+
+    match mission_operation_use_case
+        .to_cancelled(mission_id, chief_id, reason)
+        .await
+    {
+        Ok(mission_id) => (
+            StatusCode::OK,
+            format!("Mission({}) is now {:?}" , mission_id, MissionStatuses::Cancelled),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    // หมายเหตุ: ในสไลด์มีการสะกด Misssion (s 3 ตัว) ให้ตรวจสอบ Class Name ของคุณอีกครั้ง
-    let mission_operation_repository = MissionOperationPostgres::new(Arc::clone(&db_pool));
-    let mission_viewing_repository = MissionViewingPostgres::new(Arc::clone(&db_pool));
-    let use_case = MissionOperationUseCase::new(
-        Arc::new(mission_operation_repository),
-        Arc::new(mission_viewing_repository),
-    );
+pub async fn re_open<T>(
+    State(mission_operation_use_case): State<Arc<MissionOperationUseCase<T>>>,
+    AuthBrawler { id: chief_id, .. }: AuthBrawler,
+    Path(mission_id): Path<i32>,
+    change_status_model: Option<Json<ChangeStatusModel>>,
+) -> impl IntoResponse
+where
+    T: TransactionProvider + Send + Sync,
+{
+    let reason = change_status_model.and_then(|Json(model)| model.reason);
+
+    match mission_operation_use_case
+        .re_open(mission_id, chief_id, reason)
+        .await
+    {
+        Ok(mission_id) => (
+            StatusCode::OK,
+            format!("Mission({}) is now {:?}" , mission_id, MissionStatuses::Open),
+        )
+            .into_response(),
+        Err(e) => e.into_response(),
+    }
+}
 
+pub fn routes<R: Repositories>(state: &AppState<R>) -> Router {
     Router::new()
         .route("/in-progress/{mission_id}", patch(in_progress))
         .route("/to-completed/{mission_id}", patch(to_completed))
         .route("/to-failed/{mission_id}", patch(to_failed))
-        .route_layer(middleware::from_fn(authorization))
-        .with_state(Arc::new(use_case))
+        .route("/to-cancelled/{mission_id}", patch(to_cancelled))
+        .route("/re-open/{mission_id}", patch(re_open))
+        .with_state(Arc::clone(&state.mission_operation))
 }
\ No newline at end of file
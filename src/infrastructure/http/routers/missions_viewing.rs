@@ -1,8 +1,8 @@
 use std::sync::Arc;
 
 use axum::{
-    Json, Router,
-    extract::{Path, Query, State},
+    Router,
+    extract::State,
     http::StatusCode,
     response::IntoResponse,
     routing::get,
@@ -14,20 +14,21 @@ use crate::{
         repositories::mission_viewing::MissionViewingRepository,
         value_objects::mission_filter::MissionFilter,
     },
-    infrastructure::database::{
-        postgresql_connection::PgPoolSquad, repositories::mission_viewing::MissionViewingPostgres,
+    infrastructure::http::{
+        app_state::AppState,
+        extractors::{Json, Path, Query},
+        middleware::auth::OptionalAuthBrawler,
+        repositories::Repositories,
     },
 };
 
-pub fn routes(db_pool: Arc<PgPoolSquad>) -> Router {
-    let mission_viewing_repository = MissionViewingPostgres::new(db_pool);
-    let use_case = MissionViewingUseCase::new(Arc::new(mission_viewing_repository));
-
+pub fn routes<R: Repositories>(state: &AppState<R>) -> Router {
     Router::new()   
         .route("/{mission_id}", get(view_details))
         .route("/gets", get(gets))
-        .route("/crew/{mission_id}", get(get_mission_count))
-        .with_state(Arc::new(use_case))
+        .route("/crew/{mission_id}", get(get_mission_brawlers))
+        .route("/{mission_id}/history", get(get_status_history))
+        .with_state(Arc::clone(&state.mission_viewing))
 }
 
 pub async fn view_details<T>(
@@ -39,32 +40,48 @@ where
 {
     match mission_viewing_use_case.get_one(mission_id).await {
         Ok(mission_model) => (StatusCode::OK, Json(mission_model)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
 pub async fn gets<T>(
     State(mission_viewing_use_case): State<Arc<MissionViewingUseCase<T>>>,
+    OptionalAuthBrawler(viewer): OptionalAuthBrawler,
     filter: Query<MissionFilter>,
 ) -> impl IntoResponse
 where
     T: MissionViewingRepository + Send + Sync,
 {
-    match mission_viewing_use_case.get_all(&filter).await {
+    let viewer_id = viewer.map(|viewer| viewer.id);
+
+    match mission_viewing_use_case.get_all(&filter, viewer_id).await {
         Ok(mission_models) => (StatusCode::OK, Json(mission_models)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
     }
 }
 
-pub async fn get_mission_count<T>(
+pub async fn get_mission_brawlers<T>(
     State(mission_viewing_use_case): State<Arc<MissionViewingUseCase<T>>>,
     Path(mission_id): Path<i32>,
 ) -> impl IntoResponse
 where
     T: MissionViewingRepository + Send + Sync,
 {
-    match mission_viewing_use_case.get_mission_count(mission_id).await {
+    match mission_viewing_use_case.get_mission_brawlers(mission_id).await {
         Ok(brawler_models) => (StatusCode::OK, Json(brawler_models)).into_response(),
-        Err(e) => (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+pub async fn get_status_history<T>(
+    State(mission_viewing_use_case): State<Arc<MissionViewingUseCase<T>>>,
+    Path(mission_id): Path<i32>,
+) -> impl IntoResponse
+where
+    T: MissionViewingRepository + Send + Sync,
+{
+    match mission_viewing_use_case.get_status_history(mission_id).await {
+        Ok(history_models) => (StatusCode::OK, Json(history_models)).into_response(),
+        Err(e) => e.into_response(),
     }
 }
\ No newline at end of file
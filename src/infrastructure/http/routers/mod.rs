@@ -1,7 +1,9 @@
 pub mod default_routers;
+pub mod admin;
 pub mod authentication;
 pub mod brawlers;
 pub mod craw_operations;
 pub mod missions_management;
 pub mod missions_operations;
 pub mod missions_viewing;
+pub mod well_known;
@@ -0,0 +1,15 @@
+use std::sync::Arc;
+
+use axum::{Json, Router, extract::State, http::StatusCode, response::IntoResponse, routing::get};
+
+use crate::infrastructure::jwt::jwt_authority::JwtAuthority;
+
+pub fn routes(jwt_authority: Arc<JwtAuthority>) -> Router {
+    Router::new()
+        .route("/jwks.json", get(jwks))
+        .with_state(jwt_authority)
+}
+
+pub async fn jwks(State(jwt_authority): State<Arc<JwtAuthority>>) -> impl IntoResponse {
+    (StatusCode::OK, Json(jwt_authority.jwks().clone())).into_response()
+}
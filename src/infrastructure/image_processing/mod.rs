@@ -0,0 +1,72 @@
+use std::io::Cursor;
+
+use anyhow::Result;
+use image::{
+    DynamicImage, GenericImageView, ImageDecoder, ImageFormat, ImageReader, Limits,
+    imageops::{self, FilterType},
+};
+
+/// Larger uploads are refused before any pixel memory is allocated.
+const MAX_DIMENSION: u32 = 4096;
+/// Caps what a decoder may allocate, a `MAX_DIMENSION` square RGBA image takes
+/// 64 MiB.
+const MAX_ALLOC: u64 = 128 * 1024 * 1024;
+
+#[derive(Debug, Clone)]
+pub struct SquareImage {
+    pub size: u32,
+    pub png: Vec<u8>,
+}
+
+/// Decodes a PNG, JPEG or WebP image, turns it upright according to its EXIF
+/// orientation and re-encodes a center-cropped square PNG for every size.
+///
+/// The crop is averaged down to the largest size once, smaller sizes are
+/// resampled from that, so the cost of the filters does not grow with the
+/// upload.
+///
+/// Only pixels are carried over, so EXIF data such as GPS positions never
+/// reaches the storage.
+pub fn square_pngs(bytes: &[u8], sizes: &[u32]) -> Result<Vec<SquareImage>> {
+    let mut reader = ImageReader::new(Cursor::new(bytes)).with_guessed_format()?;
+    match reader.format() {
+        Some(ImageFormat::Png | ImageFormat::Jpeg | ImageFormat::WebP) => {}
+        _ => return Err(anyhow::anyhow!("Unsupported image format")),
+    }
+
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_DIMENSION);
+    limits.max_image_height = Some(MAX_DIMENSION);
+    limits.max_alloc = Some(MAX_ALLOC);
+    reader.limits(limits);
+
+    let mut decoder = reader.into_decoder()?;
+    let orientation = decoder.orientation()?;
+    let mut image = DynamicImage::from_decoder(decoder)?;
+    image.apply_orientation(orientation);
+
+    let side = image.width().min(image.height());
+    let square = image.view(
+        (image.width() - side) / 2,
+        (image.height() - side) / 2,
+        side,
+        side,
+    );
+    let largest = sizes.iter().copied().max().unwrap_or_default();
+    let largest_square = DynamicImage::ImageRgba8(imageops::thumbnail(&*square, largest, largest));
+
+    sizes
+        .iter()
+        .map(|&size| {
+            let mut png = Vec::new();
+            let resized = if size == largest {
+                largest_square.clone()
+            } else {
+                largest_square.resize_exact(size, size, FilterType::Triangle)
+            };
+            resized.write_to(&mut Cursor::new(&mut png), ImageFormat::Png)?;
+
+            Ok(SquareImage { size, png })
+        })
+        .collect()
+}
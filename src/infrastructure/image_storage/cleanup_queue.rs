@@ -0,0 +1,165 @@
+use std::{
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+use anyhow::Result;
+use tokio::sync::Notify;
+use tracing::warn;
+
+use crate::domain::repositories::{
+    image_storage::ImageStorage, pending_image_deletions::PendingImageDeletionRepository,
+};
+
+/// How often failed deletions are retried.
+const RETRY_INTERVAL: Duration = Duration::from_secs(60);
+/// Deletions still failing after this many attempts are logged and dropped.
+const MAX_ATTEMPTS: i32 = 30;
+
+/// Deletes images that are no longer referenced, away from the request that
+/// unreferenced them. [`Self::enqueue`] only hands the ids to the worker,
+/// which records them in `pending_image_deletions` before its first attempt,
+/// so failed deletions are retried in the background and across restarts.
+/// Ids enqueued right before a shutdown, not recorded yet, are lost.
+///
+/// Every server instance retries every recorded deletion, which is harmless
+/// as deleting an image that is already gone succeeds.
+pub struct ImageCleanupQueue {
+    image_storage: Arc<dyn ImageStorage + Send + Sync>,
+    pending_image_deletion_repository: Arc<dyn PendingImageDeletionRepository + Send + Sync>,
+    queued: Mutex<Vec<String>>,
+    wake: Notify,
+}
+
+impl ImageCleanupQueue {
+    pub fn new(
+        image_storage: Arc<dyn ImageStorage + Send + Sync>,
+        pending_image_deletion_repository: Arc<dyn PendingImageDeletionRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            image_storage,
+            pending_image_deletion_repository,
+            queued: Mutex::new(Vec::new()),
+            wake: Notify::new(),
+        }
+    }
+
+    /// Deletes what is enqueued as it comes in and retries failed deletions
+    /// every [`RETRY_INTERVAL`], starting with those left from before a restart.
+    pub fn spawn_worker(self: &Arc<Self>) {
+        let cleanup_queue = Arc::clone(self);
+
+        tokio::spawn(async move {
+            let mut retries = tokio::time::interval(RETRY_INTERVAL);
+
+            loop {
+                tokio::select! {
+                    _ = cleanup_queue.wake.notified() => cleanup_queue.process_queued().await,
+                    _ = retries.tick() => cleanup_queue.retry_pending().await,
+                }
+            }
+        });
+    }
+
+    /// Queues the images for deletion without waiting for the storage.
+    pub fn enqueue(&self, public_ids: Vec<String>) {
+        if public_ids.is_empty() {
+            return;
+        }
+
+        self.lock().extend(public_ids);
+        self.wake.notify_one();
+    }
+
+    /// Records what was enqueued since the last call and makes the first
+    /// attempt at deleting it.
+    pub async fn process_queued(&self) {
+        let public_ids = std::mem::take(&mut *self.lock());
+        if public_ids.is_empty() {
+            return;
+        }
+
+        if let Err(e) = self
+            .pending_image_deletion_repository
+            .add(public_ids.clone())
+            .await
+        {
+            warn!(
+                "Failed to record image deletions, failures will not be retried: {}",
+                e
+            );
+        }
+
+        for public_id in public_ids {
+            self.attempt(&public_id).await;
+        }
+    }
+
+    pub async fn retry_pending(&self) {
+        let pending = match self.pending_image_deletion_repository.pending().await {
+            Ok(pending) => pending,
+            Err(e) => {
+                warn!("Failed to load pending image deletions: {}", e);
+                return;
+            }
+        };
+
+        for pending_deletion in pending {
+            self.attempt(&pending_deletion.public_id).await;
+        }
+    }
+
+    /// Recorded deletions that have not succeeded yet.
+    pub async fn pending(&self) -> Result<Vec<String>> {
+        Ok(self
+            .pending_image_deletion_repository
+            .pending()
+            .await?
+            .into_iter()
+            .map(|pending_deletion| pending_deletion.public_id)
+            .collect())
+    }
+
+    async fn attempt(&self, public_id: &str) {
+        let Err(e) = self.image_storage.delete(public_id).await else {
+            self.forget(public_id).await;
+            return;
+        };
+
+        let attempts = match self
+            .pending_image_deletion_repository
+            .record_failure(public_id)
+            .await
+        {
+            Ok(attempts) => attempts,
+            Err(record_error) => {
+                warn!(
+                    "Failed to delete image {}, leaving it behind as the attempt could not be recorded: {} ({})",
+                    public_id, e, record_error
+                );
+                return;
+            }
+        };
+
+        if attempts >= MAX_ATTEMPTS {
+            warn!(
+                "Giving up on deleting image {} after {} attempts: {}",
+                public_id, attempts, e
+            );
+            self.forget(public_id).await;
+            return;
+        }
+
+        warn!("Failed to delete image {}, retrying later: {}", public_id, e);
+    }
+
+    async fn forget(&self, public_id: &str) {
+        if let Err(e) = self.pending_image_deletion_repository.remove(public_id).await {
+            warn!("Failed to clear the pending deletion of image {}: {}", public_id, e);
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<String>> {
+        self.queued.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
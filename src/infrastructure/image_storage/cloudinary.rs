@@ -0,0 +1,50 @@
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::{
+    config::config_model::CloudinaryEnv,
+    domain::{
+        errors::DomainError,
+        repositories::image_storage::ImageStorage,
+        value_objects::{
+            base64_image::Base64Image,
+            uploaded_image::{UploadImageOptions, UploadedImage},
+        },
+    },
+    infrastructure::cloudinary::CloudinaryClient,
+};
+
+pub struct CloudinaryImageStorage {
+    cloudinary_client: CloudinaryClient,
+}
+
+impl CloudinaryImageStorage {
+    pub fn new(cloudinary_env: CloudinaryEnv) -> Result<Self> {
+        Ok(Self {
+            cloudinary_client: CloudinaryClient::new(cloudinary_env)?,
+        })
+    }
+}
+
+#[async_trait]
+impl ImageStorage for CloudinaryImageStorage {
+    async fn upload(
+        &self,
+        base64_image: Base64Image,
+        option: UploadImageOptions,
+    ) -> Result<UploadedImage> {
+        Ok(self
+            .cloudinary_client
+            .upload(base64_image, option)
+            .await
+            .map_err(DomainError::from)?)
+    }
+
+    async fn delete(&self, public_id: &str) -> Result<()> {
+        Ok(self
+            .cloudinary_client
+            .destroy(public_id)
+            .await
+            .map_err(DomainError::from)?)
+    }
+}
@@ -0,0 +1,65 @@
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+
+use crate::{
+    config::config_model::LocalImageStorageEnv,
+    domain::{
+        repositories::image_storage::ImageStorage,
+        value_objects::{
+            base64_image::Base64Image,
+            uploaded_image::{UploadImageOptions, UploadedImage},
+        },
+    },
+    infrastructure::image_storage::{object_key, validate_key, versioned_url},
+};
+
+/// Writes images below a directory, for development and tests without network access.
+pub struct LocalImageStorage {
+    local_env: LocalImageStorageEnv,
+}
+
+impl LocalImageStorage {
+    pub fn new(local_env: LocalImageStorageEnv) -> Self {
+        Self { local_env }
+    }
+}
+
+#[async_trait]
+impl ImageStorage for LocalImageStorage {
+    async fn upload(
+        &self,
+        base64_image: Base64Image,
+        option: UploadImageOptions,
+    ) -> Result<UploadedImage> {
+        let key = object_key(&option, base64_image.extension())?;
+        let bytes = base64_image.decode()?;
+
+        let path = Path::new(&self.local_env.dir).join(&key);
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        tokio::fs::write(&path, bytes)
+            .await
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+
+        Ok(UploadedImage::new(
+            versioned_url(&self.local_env.base_url, &key),
+            key,
+        ))
+    }
+
+    async fn delete(&self, public_id: &str) -> Result<()> {
+        validate_key(public_id)?;
+
+        let path = Path::new(&self.local_env.dir).join(public_id);
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(anyhow::Error::from(e).context(format!("Failed to remove {}", path.display()))),
+        }
+    }
+}
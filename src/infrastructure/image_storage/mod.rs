@@ -0,0 +1,81 @@
+pub mod cleanup_queue;
+pub mod cloudinary;
+pub mod local;
+pub mod s3;
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use chrono::Utc;
+use uuid::Uuid;
+
+use crate::{
+    config::config_model::ImageStorageEnv,
+    domain::{
+        repositories::image_storage::ImageStorage,
+        value_objects::uploaded_image::UploadImageOptions,
+    },
+    infrastructure::image_storage::{
+        cloudinary::CloudinaryImageStorage, local::LocalImageStorage, s3::S3ImageStorage,
+    },
+};
+
+pub fn from_config(image_storage_env: &ImageStorageEnv) -> Result<Arc<dyn ImageStorage + Send + Sync>> {
+    let image_storage: Arc<dyn ImageStorage + Send + Sync> = match image_storage_env {
+        ImageStorageEnv::Cloudinary(cloudinary_env) => {
+            Arc::new(CloudinaryImageStorage::new(cloudinary_env.clone())?)
+        }
+        ImageStorageEnv::Local(local_env) => Arc::new(LocalImageStorage::new(local_env.clone())),
+        ImageStorageEnv::S3(s3_env) => Arc::new(S3ImageStorage::new(s3_env.clone())?),
+    };
+
+    Ok(image_storage)
+}
+
+/// `folder/public_id.extension`, the key the local and S3 storages file an
+/// image under. Segments are limited to a safe character set so a key can
+/// never leave the storage root.
+pub fn object_key(option: &UploadImageOptions, extension: &str) -> Result<String> {
+    let public_id = option
+        .public_id
+        .clone()
+        .unwrap_or_else(|| Uuid::new_v4().simple().to_string());
+
+    let mut segments = option
+        .folder
+        .iter()
+        .flat_map(|folder| folder.split('/'))
+        .collect::<Vec<_>>();
+    segments.push(&public_id);
+
+    let key = format!("{}.{}", segments.join("/"), extension);
+    validate_key(&key)?;
+
+    Ok(key)
+}
+
+/// Rejects keys with segments outside `[A-Za-z0-9._-]` or starting with a dot.
+pub fn validate_key(key: &str) -> Result<()> {
+    for segment in key.split('/') {
+        let is_safe = !segment.is_empty()
+            && !segment.starts_with('.')
+            && segment
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+        if !is_safe {
+            return Err(anyhow::anyhow!("Invalid image path segment: {:?}", segment));
+        }
+    }
+
+    Ok(())
+}
+
+/// Images are overwritten in place, the version query makes clients fetch the new one.
+pub fn versioned_url(base_url: &str, key: &str) -> String {
+    format!(
+        "{}/{}?v={}",
+        base_url.trim_end_matches('/'),
+        key,
+        Utc::now().timestamp_millis()
+    )
+}
@@ -0,0 +1,218 @@
+use std::time::Duration;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use reqwest::{Client, Method, Response, StatusCode, Url};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    config::config_model::S3Env,
+    domain::{
+        repositories::image_storage::ImageStorage,
+        value_objects::{
+            base64_image::Base64Image,
+            uploaded_image::{UploadImageOptions, UploadedImage},
+        },
+    },
+    infrastructure::image_storage::{object_key, validate_key, versioned_url},
+};
+
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Uploads with a plain `PUT Object` signed with AWS Signature Version 4.
+pub struct S3ImageStorage {
+    s3_env: S3Env,
+    client: Client,
+    /// `scheme://host[:port]` of the endpoint.
+    origin: String,
+    /// `host[:port]` as sent in the `Host` header.
+    host: String,
+    /// Path of the endpoint without a trailing slash, empty for most.
+    base_path: String,
+}
+
+impl S3ImageStorage {
+    pub fn new(s3_env: S3Env) -> Result<Self> {
+        let endpoint = Url::parse(&s3_env.endpoint)
+            .with_context(|| format!("S3_ENDPOINT must be an absolute URL: {}", s3_env.endpoint))?;
+        let Some(host) = endpoint.host_str() else {
+            return Err(anyhow::anyhow!("S3_ENDPOINT has no host: {}", s3_env.endpoint));
+        };
+        if endpoint.query().is_some() || endpoint.fragment().is_some() {
+            return Err(anyhow::anyhow!(
+                "S3_ENDPOINT cannot have a query or fragment: {}",
+                s3_env.endpoint
+            ));
+        }
+        // `port()` is `None` for the scheme's default port, which is left out
+        // of the `Host` header as well.
+        let host = match endpoint.port() {
+            Some(port) => format!("{}:{}", host, port),
+            None => host.to_string(),
+        };
+        let origin = format!("{}://{}", endpoint.scheme(), host);
+        let base_path = endpoint.path().trim_end_matches('/').to_string();
+
+        let client = Client::builder()
+            .connect_timeout(CONNECT_TIMEOUT)
+            .timeout(Duration::from_secs(s3_env.timeout))
+            .build()?;
+
+        Ok(Self {
+            s3_env,
+            client,
+            origin,
+            host,
+            base_path,
+        })
+    }
+
+    fn public_url(&self) -> String {
+        match &self.s3_env.public_url {
+            Some(public_url) => public_url.clone(),
+            None => format!(
+                "{}/{}",
+                self.s3_env.endpoint.trim_end_matches('/'),
+                self.s3_env.bucket
+            ),
+        }
+    }
+
+    /// Sends `body` with `extra_headers` plus the signed `host`,
+    /// `x-amz-content-sha256` and `x-amz-date` headers.
+    async fn send(
+        &self,
+        method: Method,
+        key: &str,
+        extra_headers: &[(&str, String)],
+        body: Vec<u8>,
+    ) -> Result<Response> {
+        let now = Utc::now();
+        let payload_hash = format!("{:x}", Sha256::digest(&body));
+        let canonical_uri = format!("{}/{}/{}", self.base_path, self.s3_env.bucket, key);
+
+        let mut headers = extra_headers.to_vec();
+        headers.push(("host", self.host.clone()));
+        headers.push(("x-amz-content-sha256", payload_hash.clone()));
+        headers.push(("x-amz-date", now.format("%Y%m%dT%H%M%SZ").to_string()));
+        headers.sort();
+
+        let authorization =
+            self.authorization(method.as_str(), &canonical_uri, &headers, &payload_hash, now)?;
+
+        let mut request = self
+            .client
+            .request(method, format!("{}{}", self.origin, canonical_uri));
+        for (name, value) in headers.into_iter().filter(|(name, _)| *name != "host") {
+            request = request.header(name, value);
+        }
+
+        Ok(request
+            .header("authorization", authorization)
+            .body(body)
+            .send()
+            .await?)
+    }
+
+    /// The `Authorization` header for a request with exactly `headers`, which
+    /// are lowercase and sorted by name. `canonical_uri` is the request path
+    /// as sent, already URI-encoded.
+    pub fn authorization(
+        &self,
+        method: &str,
+        canonical_uri: &str,
+        headers: &[(&str, String)],
+        payload_hash: &str,
+        now: DateTime<Utc>,
+    ) -> Result<String> {
+        let amz_date = now.format("%Y%m%dT%H%M%SZ").to_string();
+        let date = now.format("%Y%m%d").to_string();
+
+        let canonical_headers: String = headers
+            .iter()
+            .map(|(name, value)| format!("{}:{}\n", name, value.trim()))
+            .collect();
+        let signed_headers = headers
+            .iter()
+            .map(|(name, _)| *name)
+            .collect::<Vec<_>>()
+            .join(";");
+        let canonical_request = format!(
+            "{}\n{}\n\n{}\n{}\n{}",
+            method, canonical_uri, canonical_headers, signed_headers, payload_hash
+        );
+
+        let scope = format!("{}/{}/s3/aws4_request", date, self.s3_env.region);
+        let string_to_sign = format!(
+            "AWS4-HMAC-SHA256\n{}\n{}\n{:x}",
+            amz_date,
+            scope,
+            Sha256::digest(canonical_request.as_bytes())
+        );
+
+        let signing_key = [
+            date.as_str(),
+            self.s3_env.region.as_str(),
+            "s3",
+            "aws4_request",
+        ]
+        .iter()
+        .try_fold(
+            format!("AWS4{}", self.s3_env.secret_access_key).into_bytes(),
+            |key, part| hmac_sha256(&key, part.as_bytes()),
+        )?;
+        let signature = hmac_sha256(&signing_key, string_to_sign.as_bytes())?;
+
+        Ok(format!(
+            "AWS4-HMAC-SHA256 Credential={}/{}, SignedHeaders={}, Signature={}",
+            self.s3_env.access_key_id,
+            scope,
+            signed_headers,
+            hex(&signature)
+        ))
+    }
+}
+
+fn hmac_sha256(key: &[u8], data: &[u8]) -> Result<Vec<u8>> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key)?;
+    mac.update(data);
+
+    Ok(mac.finalize().into_bytes().to_vec())
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[async_trait]
+impl ImageStorage for S3ImageStorage {
+    async fn upload(
+        &self,
+        base64_image: Base64Image,
+        option: UploadImageOptions,
+    ) -> Result<UploadedImage> {
+        let key = object_key(&option, base64_image.extension())?;
+        let content_type = base64_image.mime_type().to_string();
+        let bytes = base64_image.decode()?;
+
+        self.send(Method::PUT, &key, &[("content-type", content_type)], bytes)
+            .await?
+            .error_for_status()?;
+
+        Ok(UploadedImage::new(versioned_url(&self.public_url(), &key), key))
+    }
+
+    async fn delete(&self, public_id: &str) -> Result<()> {
+        validate_key(public_id)?;
+
+        let response = self.send(Method::DELETE, public_id, &[], Vec::new()).await?;
+        // S3 answers 204 for missing keys too, some compatible stores use 404.
+        if response.status() != StatusCode::NOT_FOUND {
+            response.error_for_status()?;
+        }
+
+        Ok(())
+    }
+}
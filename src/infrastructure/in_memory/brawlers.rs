@@ -0,0 +1,213 @@
+use std::{cmp::Reverse, sync::Arc};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::result::DatabaseErrorKind;
+
+use crate::{
+    domain::{
+        entities::brawlers::{BrawlerEntity, EditBrawlerEntity, RegisterBrawlerEntity},
+        repositories::brawlers::BrawlerRepository,
+        value_objects::{
+            brawler_model::BrawlerModel, leaderboard_filter::LeaderboardSort,
+            mission_statuses::MissionStatuses, roles::Role,
+            uploaded_image::{AvatarSizes, UploadedImage},
+        },
+    },
+    infrastructure::in_memory::{InMemoryDatabase, InMemoryState, database_error, not_found, now},
+};
+
+pub struct BrawlerInMemory {
+    database: Arc<InMemoryDatabase>,
+}
+
+impl BrawlerInMemory {
+    pub fn new(database: Arc<InMemoryDatabase>) -> Self {
+        Self { database }
+    }
+}
+
+/// Same numbers as the statistics query of the Postgres repository.
+fn brawler_stats(state: &InMemoryState, brawler: &BrawlerEntity) -> BrawlerModel {
+    let joined = state
+        .crew_memberships
+        .iter()
+        .filter(|crew| crew.brawler_id == brawler.id)
+        .filter_map(|crew| state.active_mission(crew.mission_id))
+        .collect::<Vec<_>>();
+    let count_status = |status: MissionStatuses| {
+        joined.iter().filter(|mission| mission.status == status).count() as i64
+    };
+
+    BrawlerModel {
+        id: brawler.id,
+        display_name: brawler.display_name.clone(),
+        avatar_url: brawler.avatar_url.clone(),
+        avatar_sizes: brawler.avatar_sizes.clone(),
+        mission_success_count: count_status(MissionStatuses::Completed),
+        mission_joined_count: joined.len() as i64,
+        mission_failed_count: count_status(MissionStatuses::Failed),
+        mission_led_count: state
+            .missions
+            .iter()
+            .filter(|mission| mission.chief_id == brawler.id && mission.deleted_at.is_none())
+            .count() as i64,
+    }
+}
+
+fn find_brawler(state: &mut InMemoryState, brawler_id: i32) -> Result<&mut BrawlerEntity> {
+    state
+        .brawlers
+        .iter_mut()
+        .find(|brawler| brawler.id == brawler_id)
+        .ok_or_else(not_found)
+}
+
+#[async_trait]
+impl BrawlerRepository for BrawlerInMemory {
+    async fn register(&self, register_brawler_entity: RegisterBrawlerEntity) -> Result<i32> {
+        let mut state = self.database.lock();
+
+        if state
+            .brawlers
+            .iter()
+            .any(|brawler| brawler.username == register_brawler_entity.username)
+        {
+            return Err(database_error(
+                DatabaseErrorKind::UniqueViolation,
+                "duplicate key value violates unique constraint \"unique_username\"",
+            ));
+        }
+
+        let id = state.next_id("brawlers");
+        let created_at = now();
+        state.brawlers.push(BrawlerEntity {
+            id,
+            username: register_brawler_entity.username,
+            password: register_brawler_entity.password,
+            created_at,
+            updated_at: created_at,
+            display_name: register_brawler_entity.display_name,
+            avatar_url: None,
+            avatar_public_id: None,
+            role: Role::default().to_string(),
+            avatar_sizes: AvatarSizes::default(),
+        });
+
+        Ok(id)
+    }
+
+    async fn find_by_username(&self, username: &str) -> Result<BrawlerEntity> {
+        let state = self.database.lock();
+
+        state
+            .brawlers
+            .iter()
+            .find(|brawler| brawler.username == username)
+            .cloned()
+            .ok_or_else(not_found)
+    }
+
+    async fn find_by_id(&self, brawler_id: i32) -> Result<BrawlerEntity> {
+        let state = self.database.lock();
+
+        state
+            .brawlers
+            .iter()
+            .find(|brawler| brawler.id == brawler_id)
+            .cloned()
+            .ok_or_else(not_found)
+    }
+
+    async fn find_profile(&self, brawler_id: i32) -> Result<BrawlerModel> {
+        let state = self.database.lock();
+
+        let brawler = state
+            .brawlers
+            .iter()
+            .find(|brawler| brawler.id == brawler_id)
+            .ok_or_else(not_found)?;
+
+        Ok(brawler_stats(&state, brawler))
+    }
+
+    async fn leaderboard(
+        &self,
+        sort_by: LeaderboardSort,
+        limit: i64,
+        offset: i64,
+    ) -> Result<Vec<BrawlerModel>> {
+        let state = self.database.lock();
+
+        let mut result = state
+            .brawlers
+            .iter()
+            .map(|brawler| brawler_stats(&state, brawler))
+            .collect::<Vec<_>>();
+        result.sort_by_key(|brawler| {
+            let count = match sort_by {
+                LeaderboardSort::Success => brawler.mission_success_count,
+                LeaderboardSort::Joined => brawler.mission_joined_count,
+                LeaderboardSort::Failed => brawler.mission_failed_count,
+                LeaderboardSort::Led => brawler.mission_led_count,
+            };
+            (Reverse(count), brawler.id)
+        });
+
+        Ok(result
+            .into_iter()
+            .skip(offset.max(0) as usize)
+            .take(limit.max(0) as usize)
+            .collect())
+    }
+
+    async fn edit(&self, brawler_id: i32, edit_brawler_entity: EditBrawlerEntity) -> Result<()> {
+        let mut state = self.database.lock();
+        let brawler = find_brawler(&mut state, brawler_id)?;
+
+        if let Some(display_name) = edit_brawler_entity.display_name {
+            brawler.display_name = display_name;
+        }
+
+        Ok(())
+    }
+
+    async fn update_password(&self, brawler_id: i32, hashed_password: String) -> Result<()> {
+        let mut state = self.database.lock();
+        find_brawler(&mut state, brawler_id)?.password = hashed_password;
+
+        Ok(())
+    }
+
+    async fn update_role(&self, brawler_id: i32, role: Role) -> Result<()> {
+        let mut state = self.database.lock();
+        find_brawler(&mut state, brawler_id)?.role = role.to_string();
+
+        Ok(())
+    }
+
+    async fn update_avatar(
+        &self,
+        brawler_id: i32,
+        uploaded_image: UploadedImage,
+        avatar_sizes: AvatarSizes,
+    ) -> Result<()> {
+        let mut state = self.database.lock();
+        let brawler = find_brawler(&mut state, brawler_id)?;
+        brawler.avatar_url = Some(uploaded_image.url);
+        brawler.avatar_public_id = Some(uploaded_image.public_id);
+        brawler.avatar_sizes = avatar_sizes;
+
+        Ok(())
+    }
+
+    async fn remove_avatar(&self, brawler_id: i32) -> Result<()> {
+        let mut state = self.database.lock();
+        let brawler = find_brawler(&mut state, brawler_id)?;
+        brawler.avatar_url = None;
+        brawler.avatar_public_id = None;
+        brawler.avatar_sizes = AvatarSizes::default();
+
+        Ok(())
+    }
+}
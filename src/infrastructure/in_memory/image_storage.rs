@@ -0,0 +1,75 @@
+use std::{
+    collections::HashMap,
+    sync::{
+        Mutex, MutexGuard,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+use anyhow::Result;
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{
+    repositories::image_storage::ImageStorage,
+    value_objects::{
+        base64_image::Base64Image,
+        uploaded_image::{UploadImageOptions, UploadedImage},
+    },
+};
+
+/// Keeps uploaded images in memory, keyed by public id.
+#[derive(Debug, Default)]
+pub struct ImageStorageInMemory {
+    images: Mutex<HashMap<String, Vec<u8>>>,
+    fail_deletes: AtomicBool,
+}
+
+impl ImageStorageInMemory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn images(&self) -> MutexGuard<'_, HashMap<String, Vec<u8>>> {
+        self.images.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Makes `delete` fail until switched off again, to exercise cleanup retries.
+    pub fn set_fail_deletes(&self, fail_deletes: bool) {
+        self.fail_deletes.store(fail_deletes, Ordering::SeqCst);
+    }
+}
+
+#[async_trait]
+impl ImageStorage for ImageStorageInMemory {
+    async fn upload(
+        &self,
+        base64_image: Base64Image,
+        option: UploadImageOptions,
+    ) -> Result<UploadedImage> {
+        let public_id = option
+            .public_id
+            .unwrap_or_else(|| Uuid::new_v4().simple().to_string());
+        let public_id = match option.folder {
+            Some(folder) => format!("{}/{}", folder, public_id),
+            None => public_id,
+        };
+
+        self.images().insert(public_id.clone(), base64_image.decode()?);
+
+        Ok(UploadedImage::new(
+            format!("memory://images/{}", public_id),
+            public_id,
+        ))
+    }
+
+    async fn delete(&self, public_id: &str) -> Result<()> {
+        if self.fail_deletes.load(Ordering::SeqCst) {
+            return Err(anyhow::anyhow!("Image storage is unavailable"));
+        }
+
+        self.images().remove(public_id);
+
+        Ok(())
+    }
+}
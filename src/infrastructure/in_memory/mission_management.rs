@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::result::DatabaseErrorKind;
+
+use crate::{
+    domain::{
+        entities::missions::{AddMissionEntity, MissionEntity},
+        repositories::mission_management::MissionManagementRepository,
+    },
+    infrastructure::in_memory::{InMemoryDatabase, database_error, not_found, now},
+};
+
+pub struct MissionManagementInMemory {
+    database: Arc<InMemoryDatabase>,
+}
+
+impl MissionManagementInMemory {
+    pub fn new(database: Arc<InMemoryDatabase>) -> Self {
+        Self { database }
+    }
+}
+
+#[async_trait]
+impl MissionManagementRepository for MissionManagementInMemory {
+    async fn add(&self, add_mission_entity: AddMissionEntity) -> Result<i32> {
+        let mut state = self.database.lock();
+
+        if !state.brawler_exists(add_mission_entity.chief_id) {
+            return Err(database_error(
+                DatabaseErrorKind::ForeignKeyViolation,
+                "violates foreign key constraint \"fk_chief\"",
+            ));
+        }
+        if add_mission_entity.min_crew < 1
+            || add_mission_entity.max_crew < add_mission_entity.min_crew
+        {
+            return Err(database_error(
+                DatabaseErrorKind::CheckViolation,
+                "violates check constraint \"check_mission_crew_bounds\"",
+            ));
+        }
+
+        let id = state.next_id("missions");
+        let created_at = now();
+        state.missions.push(MissionEntity {
+            id,
+            name: add_mission_entity.name,
+            description: add_mission_entity.description,
+            status: add_mission_entity.status,
+            chief_id: add_mission_entity.chief_id,
+            created_at,
+            updated_at: created_at,
+            deleted_at: None,
+            min_crew: add_mission_entity.min_crew,
+            max_crew: add_mission_entity.max_crew,
+        });
+
+        Ok(id)
+    }
+
+    async fn restore(&self, mission_id: i32) -> Result<i32> {
+        let mut state = self.database.lock();
+
+        let mission = state
+            .missions
+            .iter_mut()
+            .find(|mission| mission.id == mission_id && mission.deleted_at.is_some())
+            .ok_or_else(not_found)?;
+        mission.deleted_at = None;
+
+        Ok(mission.id)
+    }
+}
@@ -0,0 +1,218 @@
+use std::{cmp::Ordering, sync::Arc};
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::{
+    domain::{
+        entities::{
+            brawler_view::BrawlerViewEntity, mission_status_history::MissionStatusHistoryEntity,
+            missions::MissionEntity,
+        },
+        repositories::mission_viewing::MissionViewingRepository,
+        value_objects::mission_filter::{MissionKeyset, MissionQuery, MissionSort, SortOrder},
+    },
+    infrastructure::in_memory::{InMemoryDatabase, InMemoryState, not_found},
+};
+
+pub struct MissionViewingInMemory {
+    database: Arc<InMemoryDatabase>,
+}
+
+impl MissionViewingInMemory {
+    pub fn new(database: Arc<InMemoryDatabase>) -> Self {
+        Self { database }
+    }
+}
+
+fn joined(state: &InMemoryState, mission_id: i32, brawler_id: i32) -> bool {
+    state
+        .crew_memberships
+        .iter()
+        .any(|crew| crew.mission_id == mission_id && crew.brawler_id == brawler_id)
+}
+
+fn matches(state: &InMemoryState, mission: &MissionEntity, mission_query: &MissionQuery) -> bool {
+    if let Some(mission_id) = mission_query.mission_id
+        && mission.id != mission_id
+    {
+        return false;
+    }
+    if !mission_query.statuses.is_empty() && !mission_query.statuses.contains(&mission.status) {
+        return false;
+    }
+    // `ILIKE '%name%'`, with the `%` and `_` wildcards inside `name` escaped.
+    if let Some(name) = &mission_query.name
+        && !mission.name.to_lowercase().contains(&name.to_lowercase())
+    {
+        return false;
+    }
+    if let Some(chief_id) = mission_query.chief_id
+        && mission.chief_id != chief_id
+    {
+        return false;
+    }
+    if let Some(brawler_id) = mission_query.joined_by
+        && !joined(state, mission.id, brawler_id)
+    {
+        return false;
+    }
+    if let Some(brawler_id) = mission_query.not_joined_by
+        && joined(state, mission.id, brawler_id)
+    {
+        return false;
+    }
+    if let Some(has_free_slots) = mission_query.has_free_slots
+        && (state.crew_count(mission.id) < mission.max_crew as i64) != has_free_slots
+    {
+        return false;
+    }
+    if let Some(created_after) = mission_query.created_after
+        && mission.created_at <= created_after
+    {
+        return false;
+    }
+    if let Some(created_before) = mission_query.created_before
+        && mission.created_at >= created_before
+    {
+        return false;
+    }
+
+    true
+}
+
+/// Ascending `(sort column, id)` order, the keyset the cursors point into.
+fn compare(sort_by: MissionSort, a: &MissionEntity, b: &MissionEntity) -> Ordering {
+    let ordering = match sort_by {
+        MissionSort::CreatedAt => a.created_at.cmp(&b.created_at),
+        MissionSort::UpdatedAt => a.updated_at.cmp(&b.updated_at),
+        MissionSort::Name => a.name.cmp(&b.name),
+    };
+
+    ordering.then(a.id.cmp(&b.id))
+}
+
+fn compare_keyset(sort_by: MissionSort, mission: &MissionEntity, keyset: &MissionKeyset) -> Ordering {
+    match keyset {
+        MissionKeyset::Timestamp(value, id) => {
+            let timestamp = match sort_by {
+                MissionSort::UpdatedAt => mission.updated_at,
+                _ => mission.created_at,
+            };
+            (timestamp, mission.id).cmp(&(*value, *id))
+        }
+        MissionKeyset::Name(value, id) => (&mission.name, mission.id).cmp(&(value, *id)),
+    }
+}
+
+#[async_trait]
+impl MissionViewingRepository for MissionViewingInMemory {
+    async fn crew_counting(&self, mission_id: i32) -> Result<i64> {
+        let state = self.database.lock();
+
+        Ok(state.crew_count(mission_id))
+    }
+
+    async fn get_one(&self, mission_id: i32) -> Result<MissionEntity> {
+        let state = self.database.lock();
+
+        state.active_mission(mission_id).cloned().ok_or_else(not_found)
+    }
+
+    async fn get_with_crew_counts(
+        &self,
+        mission_query: &MissionQuery,
+    ) -> Result<Vec<(MissionEntity, i64)>> {
+        let state = self.database.lock();
+
+        let mut missions = state
+            .missions
+            .iter()
+            .filter(|mission| mission.deleted_at.is_none())
+            .filter(|mission| matches(&state, mission, mission_query))
+            .filter(|mission| match &mission_query.after {
+                Some(keyset) => {
+                    let ordering = compare_keyset(mission_query.sort_by, mission, keyset);
+                    match mission_query.order {
+                        SortOrder::Asc => ordering == Ordering::Greater,
+                        SortOrder::Desc => ordering == Ordering::Less,
+                    }
+                }
+                None => true,
+            })
+            .collect::<Vec<_>>();
+
+        missions.sort_by(|a, b| match mission_query.order {
+            SortOrder::Asc => compare(mission_query.sort_by, a, b),
+            SortOrder::Desc => compare(mission_query.sort_by, b, a),
+        });
+
+        Ok(missions
+            .into_iter()
+            .take(mission_query.limit.max(0) as usize)
+            .map(|mission| (mission.clone(), state.crew_count(mission.id)))
+            .collect())
+    }
+
+    async fn joined_mission_ids(&self, brawler_id: i32) -> Result<Vec<i32>> {
+        let state = self.database.lock();
+
+        Ok(state
+            .crew_memberships
+            .iter()
+            .filter(|crew| crew.brawler_id == brawler_id)
+            .map(|crew| crew.mission_id)
+            .collect())
+    }
+
+    async fn get_mission_brawlers(&self, mission_id: i32) -> Result<Vec<BrawlerViewEntity>> {
+        let state = self.database.lock();
+
+        let mission = state.active_mission(mission_id).ok_or_else(not_found)?;
+        let view = |brawler_id: i32, joined_at, is_chief| {
+            state
+                .brawlers
+                .iter()
+                .find(|brawler| brawler.id == brawler_id)
+                .map(|brawler| BrawlerViewEntity {
+                    id: brawler.id,
+                    display_name: brawler.display_name.clone(),
+                    avatar_url: brawler.avatar_url.clone(),
+                    avatar_sizes: brawler.avatar_sizes.clone(),
+                    joined_at,
+                    is_chief,
+                })
+        };
+
+        let chief = view(mission.chief_id, mission.created_at, true).ok_or_else(not_found)?;
+
+        let mut crew = state
+            .crew_memberships
+            .iter()
+            .filter(|crew| crew.mission_id == mission_id)
+            .filter_map(|crew| view(crew.brawler_id, crew.joined_at, false))
+            .collect::<Vec<_>>();
+        crew.sort_by_key(|brawler| (brawler.joined_at, brawler.id));
+
+        Ok(std::iter::once(chief).chain(crew).collect())
+    }
+
+    async fn get_status_history(
+        &self,
+        mission_id: i32,
+    ) -> Result<Vec<MissionStatusHistoryEntity>> {
+        let state = self.database.lock();
+
+        state.active_mission(mission_id).ok_or_else(not_found)?;
+
+        let mut result = state
+            .mission_status_history
+            .iter()
+            .filter(|history| history.mission_id == mission_id)
+            .cloned()
+            .collect::<Vec<_>>();
+        result.sort_by_key(|history| (history.created_at, history.id));
+
+        Ok(result)
+    }
+}
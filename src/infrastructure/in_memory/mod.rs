@@ -0,0 +1,199 @@
+//! In-memory implementations of the repository traits, for tests that drive
+//! the routers without a database. Errors are raised as the same Diesel
+//! errors the Postgres repositories would return, so they map to the same
+//! `DomainError`s.
+
+pub mod brawlers;
+pub mod image_storage;
+pub mod mission_management;
+pub mod mission_viewing;
+pub mod pending_image_deletions;
+pub mod refresh_tokens;
+pub mod token_revocation;
+pub mod transaction_provider;
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use chrono::{NaiveDateTime, SubsecRound, Utc};
+use diesel::result::{DatabaseErrorKind, Error as DieselError};
+
+use crate::{
+    domain::{
+        entities::{
+            brawlers::BrawlerEntity, mission_status_history::MissionStatusHistoryEntity,
+            missions::MissionEntity, pending_image_deletions::PendingImageDeletionEntity,
+            refresh_tokens::RefreshTokenEntity,
+            revoked_tokens::RevokedTokenEntity,
+        },
+        value_objects::mission_statuses::MissionStatuses,
+    },
+    infrastructure::{
+        http::repositories::Repositories,
+        in_memory::{
+            brawlers::BrawlerInMemory, mission_management::MissionManagementInMemory,
+            mission_viewing::MissionViewingInMemory,
+            pending_image_deletions::PendingImageDeletionInMemory,
+            refresh_tokens::RefreshTokenInMemory, token_revocation::TokenRevocationInMemory,
+            transaction_provider::InMemoryTransactionProvider,
+        },
+    },
+};
+
+#[derive(Debug, Clone)]
+pub struct CrewMembershipRow {
+    pub mission_id: i32,
+    pub brawler_id: i32,
+    pub joined_at: NaiveDateTime,
+}
+
+/// Every table the repositories touch. Cloned as a whole to run a transaction
+/// against, and swapped back in on commit.
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryState {
+    pub brawlers: Vec<BrawlerEntity>,
+    pub sessions_revoked_at: HashMap<i32, NaiveDateTime>,
+    pub missions: Vec<MissionEntity>,
+    pub crew_memberships: Vec<CrewMembershipRow>,
+    pub mission_status_history: Vec<MissionStatusHistoryEntity>,
+    pub refresh_tokens: Vec<RefreshTokenEntity>,
+    pub revoked_tokens: Vec<RevokedTokenEntity>,
+    pub pending_image_deletions: Vec<PendingImageDeletionEntity>,
+    sequences: HashMap<&'static str, i32>,
+}
+
+impl InMemoryState {
+    /// Like a `SERIAL` column, ids are never handed out twice.
+    pub fn next_id(&mut self, table: &'static str) -> i32 {
+        let id = self.sequences.entry(table).or_insert(0);
+        *id += 1;
+
+        *id
+    }
+
+    pub fn brawler_exists(&self, brawler_id: i32) -> bool {
+        self.brawlers.iter().any(|brawler| brawler.id == brawler_id)
+    }
+
+    pub fn active_mission(&self, mission_id: i32) -> Option<&MissionEntity> {
+        self.missions
+            .iter()
+            .find(|mission| mission.id == mission_id && mission.deleted_at.is_none())
+    }
+
+    pub fn open_mission(&mut self, mission_id: i32) -> Option<&mut MissionEntity> {
+        self.missions.iter_mut().find(|mission| {
+            mission.id == mission_id
+                && mission.deleted_at.is_none()
+                && mission.status == MissionStatuses::Open
+        })
+    }
+
+    pub fn crew_count(&self, mission_id: i32) -> i64 {
+        self.crew_memberships
+            .iter()
+            .filter(|crew| crew.mission_id == mission_id)
+            .count() as i64
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct InMemoryDatabase {
+    state: Mutex<InMemoryState>,
+}
+
+impl InMemoryDatabase {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn lock(&self) -> MutexGuard<'_, InMemoryState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Postgres `now()`, which only keeps microseconds.
+pub fn now() -> NaiveDateTime {
+    Utc::now().naive_utc().trunc_subsecs(6)
+}
+
+pub fn not_found() -> anyhow::Error {
+    DieselError::NotFound.into()
+}
+
+pub fn database_error(kind: DatabaseErrorKind, message: &str) -> anyhow::Error {
+    DieselError::DatabaseError(kind, Box::new(message.to_string())).into()
+}
+
+/// All in-memory repositories, sharing one [`InMemoryDatabase`].
+pub struct InMemoryRepositories {
+    brawler: Arc<BrawlerInMemory>,
+    refresh_token: Arc<RefreshTokenInMemory>,
+    token_revocation: Arc<TokenRevocationInMemory>,
+    mission_management: Arc<MissionManagementInMemory>,
+    mission_viewing: Arc<MissionViewingInMemory>,
+    pending_image_deletion: Arc<PendingImageDeletionInMemory>,
+    transaction_provider: Arc<InMemoryTransactionProvider>,
+}
+
+impl InMemoryRepositories {
+    pub fn new(database: Arc<InMemoryDatabase>) -> Self {
+        Self {
+            brawler: Arc::new(BrawlerInMemory::new(Arc::clone(&database))),
+            refresh_token: Arc::new(RefreshTokenInMemory::new(Arc::clone(&database))),
+            token_revocation: Arc::new(TokenRevocationInMemory::new(Arc::clone(&database))),
+            mission_management: Arc::new(MissionManagementInMemory::new(Arc::clone(&database))),
+            mission_viewing: Arc::new(MissionViewingInMemory::new(Arc::clone(&database))),
+            pending_image_deletion: Arc::new(PendingImageDeletionInMemory::new(Arc::clone(
+                &database,
+            ))),
+            transaction_provider: Arc::new(InMemoryTransactionProvider::new(database)),
+        }
+    }
+}
+
+impl Default for InMemoryRepositories {
+    fn default() -> Self {
+        Self::new(Arc::new(InMemoryDatabase::new()))
+    }
+}
+
+impl Repositories for InMemoryRepositories {
+    type Brawler = BrawlerInMemory;
+    type RefreshToken = RefreshTokenInMemory;
+    type TokenRevocation = TokenRevocationInMemory;
+    type MissionManagement = MissionManagementInMemory;
+    type MissionViewing = MissionViewingInMemory;
+    type PendingImageDeletion = PendingImageDeletionInMemory;
+    type Transaction = InMemoryTransactionProvider;
+
+    fn brawler(&self) -> Arc<Self::Brawler> {
+        Arc::clone(&self.brawler)
+    }
+
+    fn refresh_token(&self) -> Arc<Self::RefreshToken> {
+        Arc::clone(&self.refresh_token)
+    }
+
+    fn token_revocation(&self) -> Arc<Self::TokenRevocation> {
+        Arc::clone(&self.token_revocation)
+    }
+
+    fn mission_management(&self) -> Arc<Self::MissionManagement> {
+        Arc::clone(&self.mission_management)
+    }
+
+    fn mission_viewing(&self) -> Arc<Self::MissionViewing> {
+        Arc::clone(&self.mission_viewing)
+    }
+
+    fn pending_image_deletion(&self) -> Arc<Self::PendingImageDeletion> {
+        Arc::clone(&self.pending_image_deletion)
+    }
+
+    fn transaction_provider(&self) -> Arc<Self::Transaction> {
+        Arc::clone(&self.transaction_provider)
+    }
+}
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+use crate::{
+    domain::{
+        entities::pending_image_deletions::PendingImageDeletionEntity,
+        repositories::pending_image_deletions::PendingImageDeletionRepository,
+    },
+    infrastructure::in_memory::{InMemoryDatabase, not_found, now},
+};
+
+pub struct PendingImageDeletionInMemory {
+    database: Arc<InMemoryDatabase>,
+}
+
+impl PendingImageDeletionInMemory {
+    pub fn new(database: Arc<InMemoryDatabase>) -> Self {
+        Self { database }
+    }
+}
+
+#[async_trait]
+impl PendingImageDeletionRepository for PendingImageDeletionInMemory {
+    async fn add(&self, public_ids: Vec<String>) -> Result<()> {
+        let mut state = self.database.lock();
+        let queued_at = now();
+
+        for public_id in public_ids {
+            if state
+                .pending_image_deletions
+                .iter()
+                .any(|pending| pending.public_id == public_id)
+            {
+                continue;
+            }
+
+            state.pending_image_deletions.push(PendingImageDeletionEntity {
+                public_id,
+                attempts: 0,
+                queued_at,
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn pending(&self) -> Result<Vec<PendingImageDeletionEntity>> {
+        let state = self.database.lock();
+
+        let mut pending = state.pending_image_deletions.clone();
+        pending.sort_by(|a, b| (a.queued_at, &a.public_id).cmp(&(b.queued_at, &b.public_id)));
+
+        Ok(pending)
+    }
+
+    async fn record_failure(&self, public_id: &str) -> Result<i32> {
+        let mut state = self.database.lock();
+
+        let pending = state
+            .pending_image_deletions
+            .iter_mut()
+            .find(|pending| pending.public_id == public_id)
+            .ok_or_else(not_found)?;
+        pending.attempts += 1;
+
+        Ok(pending.attempts)
+    }
+
+    async fn remove(&self, public_id: &str) -> Result<()> {
+        let mut state = self.database.lock();
+
+        state
+            .pending_image_deletions
+            .retain(|pending| pending.public_id != public_id);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,121 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::result::DatabaseErrorKind;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::refresh_tokens::{AddRefreshTokenEntity, RefreshTokenEntity},
+        repositories::refresh_tokens::RefreshTokenRepository,
+    },
+    infrastructure::in_memory::{InMemoryDatabase, InMemoryState, database_error, not_found, now},
+};
+
+pub struct RefreshTokenInMemory {
+    database: Arc<InMemoryDatabase>,
+}
+
+impl RefreshTokenInMemory {
+    pub fn new(database: Arc<InMemoryDatabase>) -> Self {
+        Self { database }
+    }
+}
+
+fn insert(state: &mut InMemoryState, add_refresh_token_entity: AddRefreshTokenEntity) -> Result<()> {
+    if !state.brawler_exists(add_refresh_token_entity.brawler_id) {
+        return Err(database_error(
+            DatabaseErrorKind::ForeignKeyViolation,
+            "violates foreign key constraint \"fk_refresh_token_brawler\"",
+        ));
+    }
+    if state
+        .refresh_tokens
+        .iter()
+        .any(|token| token.token_hash == add_refresh_token_entity.token_hash)
+    {
+        return Err(database_error(
+            DatabaseErrorKind::UniqueViolation,
+            "duplicate key value violates unique constraint \"unique_refresh_token_hash\"",
+        ));
+    }
+
+    let id = state.next_id("refresh_tokens");
+    state.refresh_tokens.push(RefreshTokenEntity {
+        id,
+        brawler_id: add_refresh_token_entity.brawler_id,
+        family_id: add_refresh_token_entity.family_id,
+        token_hash: add_refresh_token_entity.token_hash,
+        expires_at: add_refresh_token_entity.expires_at,
+        used_at: None,
+        revoked_at: None,
+        created_at: now(),
+    });
+
+    Ok(())
+}
+
+#[async_trait]
+impl RefreshTokenRepository for RefreshTokenInMemory {
+    async fn add(&self, add_refresh_token_entity: AddRefreshTokenEntity) -> Result<()> {
+        let mut state = self.database.lock();
+
+        insert(&mut state, add_refresh_token_entity)
+    }
+
+    async fn find_by_hash(&self, token_hash: &str) -> Result<RefreshTokenEntity> {
+        let state = self.database.lock();
+
+        state
+            .refresh_tokens
+            .iter()
+            .find(|token| token.token_hash == token_hash)
+            .cloned()
+            .ok_or_else(not_found)
+    }
+
+    async fn rotate(
+        &self,
+        used_token_id: i32,
+        add_refresh_token_entity: AddRefreshTokenEntity,
+    ) -> Result<bool> {
+        let mut state = self.database.lock();
+
+        let Some(index) = state.refresh_tokens.iter().position(|token| {
+            token.id == used_token_id && token.used_at.is_none() && token.revoked_at.is_none()
+        }) else {
+            return Ok(false);
+        };
+
+        // Inserting first leaves the used token untouched if the insert fails.
+        insert(&mut state, add_refresh_token_entity)?;
+        state.refresh_tokens[index].used_at = Some(now());
+
+        Ok(true)
+    }
+
+    async fn revoke_family(&self, family_id: Uuid) -> Result<()> {
+        let mut state = self.database.lock();
+
+        state
+            .refresh_tokens
+            .iter_mut()
+            .filter(|token| token.family_id == family_id && token.revoked_at.is_none())
+            .for_each(|token| token.revoked_at = Some(now()));
+
+        Ok(())
+    }
+
+    async fn revoke_all(&self, brawler_id: i32) -> Result<()> {
+        let mut state = self.database.lock();
+
+        state
+            .refresh_tokens
+            .iter_mut()
+            .filter(|token| token.brawler_id == brawler_id && token.revoked_at.is_none())
+            .for_each(|token| token.revoked_at = Some(now()));
+
+        Ok(())
+    }
+}
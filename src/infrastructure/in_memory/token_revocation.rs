@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use diesel::result::DatabaseErrorKind;
+
+use crate::{
+    domain::{
+        entities::revoked_tokens::{AddRevokedTokenEntity, RevokedTokenEntity},
+        repositories::token_revocation::TokenRevocationRepository,
+    },
+    infrastructure::in_memory::{InMemoryDatabase, database_error, not_found, now},
+};
+
+pub struct TokenRevocationInMemory {
+    database: Arc<InMemoryDatabase>,
+}
+
+impl TokenRevocationInMemory {
+    pub fn new(database: Arc<InMemoryDatabase>) -> Self {
+        Self { database }
+    }
+}
+
+#[async_trait]
+impl TokenRevocationRepository for TokenRevocationInMemory {
+    async fn revoke(&self, add_revoked_token_entity: AddRevokedTokenEntity) -> Result<()> {
+        let mut state = self.database.lock();
+
+        if !state.brawler_exists(add_revoked_token_entity.brawler_id) {
+            return Err(database_error(
+                DatabaseErrorKind::ForeignKeyViolation,
+                "violates foreign key constraint \"fk_revoked_token_brawler\"",
+            ));
+        }
+        if state
+            .revoked_tokens
+            .iter()
+            .any(|revoked| revoked.jti == add_revoked_token_entity.jti)
+        {
+            return Ok(());
+        }
+
+        state.revoked_tokens.push(RevokedTokenEntity {
+            jti: add_revoked_token_entity.jti,
+            brawler_id: add_revoked_token_entity.brawler_id,
+            expires_at: add_revoked_token_entity.expires_at,
+            revoked_at: now(),
+        });
+
+        Ok(())
+    }
+
+    async fn revoke_all(&self, brawler_id: i32) -> Result<NaiveDateTime> {
+        let mut state = self.database.lock();
+
+        if !state.brawler_exists(brawler_id) {
+            return Err(not_found());
+        }
+
+        let cutoff = now();
+        state.sessions_revoked_at.insert(brawler_id, cutoff);
+
+        Ok(cutoff)
+    }
+
+    async fn active_revocations(&self) -> Result<Vec<RevokedTokenEntity>> {
+        let state = self.database.lock();
+        let now = now();
+
+        Ok(state
+            .revoked_tokens
+            .iter()
+            .filter(|revoked| revoked.expires_at > now)
+            .cloned()
+            .collect())
+    }
+
+    async fn session_cutoffs(&self) -> Result<Vec<(i32, NaiveDateTime)>> {
+        let state = self.database.lock();
+
+        Ok(state
+            .sessions_revoked_at
+            .iter()
+            .map(|(brawler_id, cutoff)| (*brawler_id, *cutoff))
+            .collect())
+    }
+
+    async fn remove_expired(&self) -> Result<usize> {
+        let mut state = self.database.lock();
+        let now = now();
+
+        let before = state.revoked_tokens.len();
+        state.revoked_tokens.retain(|revoked| revoked.expires_at > now);
+
+        Ok(before - state.revoked_tokens.len())
+    }
+}
@@ -0,0 +1,158 @@
+use std::sync::Arc;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use diesel::result::DatabaseErrorKind;
+
+use crate::{
+    domain::{
+        entities::{
+            crew_memberships::CrewMembershipEntity,
+            mission_status_history::{AddMissionStatusHistoryEntity, MissionStatusHistoryEntity},
+            missions::{EditMissionEntity, MissionEntity},
+        },
+        errors::DomainResult,
+        repositories::transaction_provider::{MissionTransaction, TransactionProvider},
+    },
+    infrastructure::in_memory::{
+        CrewMembershipRow, InMemoryDatabase, InMemoryState, database_error, not_found, now,
+    },
+};
+
+pub struct InMemoryTransactionProvider {
+    database: Arc<InMemoryDatabase>,
+}
+
+impl InMemoryTransactionProvider {
+    pub fn new(database: Arc<InMemoryDatabase>) -> Self {
+        Self { database }
+    }
+}
+
+#[async_trait]
+impl TransactionProvider for InMemoryTransactionProvider {
+    type Transaction = InMemoryState;
+
+    /// Holds the database lock for the whole transaction and works on a copy
+    /// of the state, which replaces the original only when `f` succeeds.
+    async fn transaction<T, F>(&self, f: F) -> DomainResult<T>
+    where
+        F: FnOnce(&mut Self::Transaction) -> DomainResult<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let mut state = self.database.lock();
+        let mut transaction = state.clone();
+
+        let result = f(&mut transaction)?;
+        *state = transaction;
+
+        Ok(result)
+    }
+}
+
+impl MissionTransaction for InMemoryState {
+    fn lock_mission(&mut self, mission_id: i32) -> Result<MissionEntity> {
+        self.active_mission(mission_id).cloned().ok_or_else(not_found)
+    }
+
+    fn crew_count(&mut self, mission_id: i32) -> Result<i64> {
+        Ok(InMemoryState::crew_count(self, mission_id))
+    }
+
+    fn edit_mission(&mut self, mission_id: i32, edit_mission_entity: EditMissionEntity) -> Result<i32> {
+        let mission = self.open_mission(mission_id).ok_or_else(not_found)?;
+
+        mission.chief_id = edit_mission_entity.chief_id;
+        if let Some(name) = edit_mission_entity.name {
+            mission.name = name;
+        }
+        if let Some(description) = edit_mission_entity.description {
+            mission.description = Some(description);
+        }
+
+        Ok(mission.id)
+    }
+
+    fn remove_mission(&mut self, mission_id: i32, chief_id: i32) -> Result<()> {
+        let mission = self.open_mission(mission_id).ok_or_else(not_found)?;
+        let now = now();
+        mission.deleted_at = Some(now);
+        mission.updated_at = now;
+        mission.chief_id = chief_id;
+
+        Ok(())
+    }
+
+    fn join(&mut self, crew_membership_entity: CrewMembershipEntity) -> Result<()> {
+        let CrewMembershipEntity {
+            brawler_id,
+            mission_id,
+        } = crew_membership_entity;
+
+        if !self.brawler_exists(brawler_id)
+            || !self.missions.iter().any(|mission| mission.id == mission_id)
+        {
+            return Err(database_error(
+                DatabaseErrorKind::ForeignKeyViolation,
+                "violates foreign key constraint on \"crew_memberships\"",
+            ));
+        }
+        if self
+            .crew_memberships
+            .iter()
+            .any(|crew| crew.brawler_id == brawler_id && crew.mission_id == mission_id)
+        {
+            return Err(database_error(
+                DatabaseErrorKind::UniqueViolation,
+                "duplicate key value violates unique constraint \"crew_memberships_pkey\"",
+            ));
+        }
+
+        self.crew_memberships.push(CrewMembershipRow {
+            mission_id,
+            brawler_id,
+            joined_at: now(),
+        });
+
+        Ok(())
+    }
+
+    fn leave(&mut self, crew_membership_entity: CrewMembershipEntity) -> Result<()> {
+        let before = self.crew_memberships.len();
+        self.crew_memberships.retain(|crew| {
+            crew.brawler_id != crew_membership_entity.brawler_id
+                || crew.mission_id != crew_membership_entity.mission_id
+        });
+        if self.crew_memberships.len() == before {
+            return Err(not_found());
+        }
+
+        Ok(())
+    }
+
+    fn change_status(&mut self, transition: AddMissionStatusHistoryEntity) -> Result<()> {
+        let mission = self
+            .missions
+            .iter_mut()
+            .find(|mission| {
+                mission.id == transition.mission_id && mission.status == transition.from_status
+            })
+            .ok_or_else(not_found)?;
+        let now = now();
+        mission.status = transition.to_status;
+        mission.updated_at = now;
+
+        let id = self.next_id("mission_status_history");
+        self.mission_status_history.push(MissionStatusHistoryEntity {
+            id,
+            mission_id: transition.mission_id,
+            from_status: transition.from_status,
+            to_status: transition.to_status,
+            actor_id: transition.actor_id,
+            reason: transition.reason,
+            created_at: now,
+        });
+
+        Ok(())
+    }
+}
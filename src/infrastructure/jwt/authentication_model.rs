@@ -4,4 +4,14 @@ use serde::{Deserialize, Serialize};
 pub struct LoginModel {
     pub username: String,
     pub password: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefreshTokenModel {
+    pub refresh_token: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LogoutModel {
+    pub refresh_token: Option<String>,
 }
\ No newline at end of file
@@ -0,0 +1,254 @@
+use std::{collections::HashMap, str::FromStr};
+
+use anyhow::{Context, Result};
+use base64::{Engine as _, engine::general_purpose};
+use chrono::Duration;
+use jsonwebtoken::{
+    Algorithm, DecodingKey, EncodingKey, Header, Validation, decode, decode_header, encode,
+    jwk::{
+        AlgorithmParameters, CommonParameters, EllipticCurve, Jwk, JwkSet, KeyAlgorithm,
+        OctetKeyPairParameters, OctetKeyPairType, PublicKeyUse, RSAKeyParameters, RSAKeyType,
+    },
+};
+use simple_asn1::{ASN1Block, OID, oid};
+
+use crate::{
+    config::config_model::{JwtEnv, JwtKeyEnv},
+    infrastructure::jwt::jwt_model::Claims,
+};
+
+struct VerificationKey {
+    algorithm: Algorithm,
+    decoding_key: DecodingKey,
+}
+
+/// Signs and verifies brawler tokens with the key set configured at startup.
+pub struct JwtAuthority {
+    signing_key_id: String,
+    signing_algorithm: Algorithm,
+    encoding_key: EncodingKey,
+    verification_keys: HashMap<String, VerificationKey>,
+    jwks: JwkSet,
+    access_token_lifetime: Duration,
+    refresh_token_lifetime: Duration,
+}
+
+impl JwtAuthority {
+    pub fn from_env(jwt_env: &JwtEnv) -> Result<Self> {
+        let signing_env = &jwt_env.signing_key;
+        let signing_algorithm = parse_algorithm(&signing_env.algorithm)?;
+
+        let encoding_key = match signing_algorithm {
+            Algorithm::HS256 => {
+                let secret = signing_env
+                    .secret
+                    .as_ref()
+                    .context("JWT_USER_SECRET is required for HS256")?;
+                EncodingKey::from_secret(secret.as_bytes())
+            }
+            algorithm => {
+                let private_pem =
+                    read_key(signing_env.private_key_path.as_ref(), "JWT_PRIVATE_KEY_PATH")?;
+                match algorithm {
+                    Algorithm::RS256 => EncodingKey::from_rsa_pem(&private_pem)?,
+                    _ => EncodingKey::from_ed_pem(&private_pem)?,
+                }
+            }
+        };
+
+        let mut verification_keys = HashMap::new();
+        let mut jwks = JwkSet { keys: Vec::new() };
+
+        for key_env in std::iter::once(signing_env).chain(jwt_env.verification_keys.iter()) {
+            let algorithm = parse_algorithm(&key_env.algorithm)?;
+
+            let decoding_key = match algorithm {
+                Algorithm::HS256 => {
+                    let secret = key_env
+                        .secret
+                        .as_ref()
+                        .context("HS256 verification keys need a secret")?;
+                    DecodingKey::from_secret(secret.as_bytes())
+                }
+                _ => {
+                    let public_pem =
+                        read_key(key_env.public_key_path.as_ref(), "JWT_PUBLIC_KEY_PATH")?;
+                    jwks.keys.push(
+                        public_jwk(key_env, algorithm, &public_pem).with_context(|| {
+                            format!("Invalid public key for JWT key {}", key_env.key_id)
+                        })?,
+                    );
+
+                    match algorithm {
+                        Algorithm::RS256 => DecodingKey::from_rsa_pem(&public_pem)?,
+                        _ => DecodingKey::from_ed_pem(&public_pem)?,
+                    }
+                }
+            };
+
+            if verification_keys
+                .insert(
+                    key_env.key_id.clone(),
+                    VerificationKey {
+                        algorithm,
+                        decoding_key,
+                    },
+                )
+                .is_some()
+            {
+                return Err(anyhow::anyhow!("Duplicate JWT key id: {}", key_env.key_id));
+            }
+        }
+
+        Ok(Self {
+            signing_key_id: signing_env.key_id.clone(),
+            signing_algorithm,
+            encoding_key,
+            verification_keys,
+            jwks,
+            access_token_lifetime: Duration::minutes(jwt_env.access_token_lifetime_minutes),
+            refresh_token_lifetime: Duration::days(jwt_env.refresh_token_lifetime_days),
+        })
+    }
+
+    pub fn sign(&self, claims: &Claims) -> Result<String> {
+        let mut header = Header::new(self.signing_algorithm);
+        header.kid = Some(self.signing_key_id.clone());
+
+        let token = encode(&header, claims, &self.encoding_key)?;
+
+        Ok(token)
+    }
+
+    pub fn verify(&self, token: &str) -> Result<Claims> {
+        let header = decode_header(token)?;
+        // Tokens issued before key ids were introduced carry no `kid`.
+        let key_id = header.kid.as_ref().unwrap_or(&self.signing_key_id);
+
+        let verification_key = self
+            .verification_keys
+            .get(key_id)
+            .ok_or_else(|| anyhow::anyhow!("Unknown JWT key id: {}", key_id))?;
+
+        let token = decode::<Claims>(
+            token,
+            &verification_key.decoding_key,
+            &Validation::new(verification_key.algorithm),
+        )?;
+
+        Ok(token.claims)
+    }
+
+    /// Public keys only, HS256 secrets are never published.
+    pub fn jwks(&self) -> &JwkSet {
+        &self.jwks
+    }
+
+    pub fn access_token_lifetime(&self) -> Duration {
+        self.access_token_lifetime
+    }
+
+    pub fn refresh_token_lifetime(&self) -> Duration {
+        self.refresh_token_lifetime
+    }
+}
+
+fn parse_algorithm(algorithm: &str) -> Result<Algorithm> {
+    match Algorithm::from_str(algorithm)? {
+        algorithm @ (Algorithm::HS256 | Algorithm::RS256 | Algorithm::EdDSA) => Ok(algorithm),
+        other => Err(anyhow::anyhow!("Unsupported JWT algorithm: {:?}", other)),
+    }
+}
+
+fn read_key(path: Option<&String>, variable: &str) -> Result<Vec<u8>> {
+    let path = path.with_context(|| format!("{} is not set", variable))?;
+
+    std::fs::read(path).with_context(|| format!("Failed to read key file {}", path))
+}
+
+fn public_jwk(key_env: &JwtKeyEnv, algorithm: Algorithm, public_pem: &[u8]) -> Result<Jwk> {
+    let (key_algorithm, parameters) = match algorithm {
+        Algorithm::RS256 => {
+            let (n, e) = rsa_public_components(public_pem)?;
+            (
+                KeyAlgorithm::RS256,
+                AlgorithmParameters::RSA(RSAKeyParameters {
+                    key_type: RSAKeyType::RSA,
+                    n: general_purpose::URL_SAFE_NO_PAD.encode(n),
+                    e: general_purpose::URL_SAFE_NO_PAD.encode(e),
+                }),
+            )
+        }
+        _ => (
+            KeyAlgorithm::EdDSA,
+            AlgorithmParameters::OctetKeyPair(OctetKeyPairParameters {
+                key_type: OctetKeyPairType::OctetKeyPair,
+                curve: EllipticCurve::Ed25519,
+                x: general_purpose::URL_SAFE_NO_PAD.encode(ed25519_public_key(public_pem)?),
+            }),
+        ),
+    };
+
+    Ok(Jwk {
+        common: CommonParameters {
+            public_key_use: Some(PublicKeyUse::Signature),
+            key_algorithm: Some(key_algorithm),
+            key_id: Some(key_env.key_id.clone()),
+            ..Default::default()
+        },
+        algorithm: parameters,
+    })
+}
+
+/// Raw key bytes from the BIT STRING of a `PUBLIC KEY` (SubjectPublicKeyInfo)
+/// PEM, which must be for `algorithm`: an Ed25519 key where RS256 is configured
+/// is a configuration error, not a key to publish.
+fn subject_public_key(public_pem: &[u8], algorithm: &OID, algorithm_name: &str) -> Result<Vec<u8>> {
+    let pem = pem::parse(public_pem)?;
+    if pem.tag() != "PUBLIC KEY" {
+        return Err(anyhow::anyhow!("Expected a PUBLIC KEY pem, got {}", pem.tag()));
+    }
+
+    let malformed = || anyhow::anyhow!("Malformed public key");
+    let der = simple_asn1::from_der(pem.contents())?;
+    let [ASN1Block::Sequence(_, blocks)] = der.as_slice() else {
+        return Err(malformed());
+    };
+    let [ASN1Block::Sequence(_, identifier), ASN1Block::BitString(_, _, key)] = blocks.as_slice()
+    else {
+        return Err(malformed());
+    };
+    let Some(ASN1Block::ObjectIdentifier(_, key_algorithm)) = identifier.first() else {
+        return Err(malformed());
+    };
+    if key_algorithm != algorithm {
+        return Err(anyhow::anyhow!("Expected an {} public key", algorithm_name));
+    }
+
+    Ok(key.clone())
+}
+
+/// `rsaEncryption` keys, as modulus and exponent.
+fn rsa_public_components(public_pem: &[u8]) -> Result<(Vec<u8>, Vec<u8>)> {
+    let rsa_key = subject_public_key(public_pem, &oid!(1, 2, 840, 113549, 1, 1, 1), "rsaEncryption")?;
+
+    match simple_asn1::from_der(&rsa_key)?.as_slice() {
+        [ASN1Block::Sequence(_, blocks)] => match blocks.as_slice() {
+            [ASN1Block::Integer(_, n), ASN1Block::Integer(_, e)] => {
+                Ok((n.to_bytes_be().1, e.to_bytes_be().1))
+            }
+            _ => Err(anyhow::anyhow!("Malformed RSA public key")),
+        },
+        _ => Err(anyhow::anyhow!("Malformed RSA public key")),
+    }
+}
+
+/// `id-Ed25519` keys, as their 32 raw bytes.
+fn ed25519_public_key(public_pem: &[u8]) -> Result<Vec<u8>> {
+    let key = subject_public_key(public_pem, &oid!(1, 3, 101, 112), "id-Ed25519")?;
+    if key.len() != 32 {
+        return Err(anyhow::anyhow!("Malformed Ed25519 public key"));
+    }
+
+    Ok(key)
+}
\ No newline at end of file
@@ -1,8 +1,9 @@
-use chrono::{Duration, Utc};
+use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use anyhow::Result;
+use uuid::Uuid;
 
-use crate::{config::config_loader::get_jwt_env, infrastructure::jwt::generate_token};
+use crate::{domain::value_objects::roles::Role, infrastructure::jwt::jwt_authority::JwtAuthority};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Passport {
@@ -11,33 +12,46 @@ pub struct Passport {
     pub(crate) token_type: String,
     pub(crate) expires_in: usize,
     // pub expires_in: usize,
-    // pub(crate) refresh_token: String,
+    pub refresh_token: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Claims {
     pub sub: String,
     pub exp: usize,
-    pub iat: usize,
+    /// Seconds with microsecond precision, so tokens issued right after a
+    /// session cutoff are told apart from the ones it revokes.
+    pub iat: f64,
+    pub jti: String,
+    // Tokens issued before roles existed carry none and fall back to the least privileged one.
+    #[serde(default)]
+    pub role: Role,
 }
 
 impl Passport {
-    pub fn new(brawler_id: i32) -> Result<Self> {
-        let jwt_env = get_jwt_env()?;
+    pub fn new(
+        brawler_id: i32,
+        role: Role,
+        refresh_token: String,
+        jwt_authority: &JwtAuthority,
+    ) -> Result<Self> {
         let token_type = "Bearer".to_string();
-        let expires_in = (Utc::now() + Duration::days(jwt_env.lift_time_days)).timestamp() as usize;
+        let expires_in = (Utc::now() + jwt_authority.access_token_lifetime()).timestamp() as usize;
 
         let access_token_claims = Claims {
             sub: brawler_id.to_string(),
             exp: expires_in,
-            iat: Utc::now().timestamp() as usize,
+            iat: Utc::now().timestamp_micros() as f64 / 1_000_000.0,
+            jti: Uuid::new_v4().to_string(),
+            role,
         };
-        let access_token = generate_token(jwt_env.secret, &access_token_claims)?;
+        let access_token = jwt_authority.sign(&access_token_claims)?;
 
         Ok(Self {
             token_type,
             access_token,
             expires_in,
+            refresh_token,
         })
     }
 }
\ No newline at end of file
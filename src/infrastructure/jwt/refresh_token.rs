@@ -0,0 +1,32 @@
+use base64::{Engine as _, engine::general_purpose};
+use chrono::{Duration, Utc};
+use rand::{RngCore, rngs::OsRng};
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+use crate::domain::entities::refresh_tokens::AddRefreshTokenEntity;
+
+pub struct IssuedRefreshToken {
+    pub token: String,
+    pub entity: AddRefreshTokenEntity,
+}
+
+/// Creates an opaque refresh token. Only its hash is meant to be persisted.
+pub fn issue(brawler_id: i32, family_id: Uuid, lifetime: Duration) -> IssuedRefreshToken {
+    let mut bytes = [0u8; 32];
+    OsRng.fill_bytes(&mut bytes);
+    let token = general_purpose::URL_SAFE_NO_PAD.encode(bytes);
+
+    let entity = AddRefreshTokenEntity {
+        brawler_id,
+        family_id,
+        token_hash: hash(&token),
+        expires_at: (Utc::now() + lifetime).naive_utc(),
+    };
+
+    IssuedRefreshToken { token, entity }
+}
+
+pub fn hash(token: &str) -> String {
+    format!("{:x}", Sha256::digest(token.as_bytes()))
+}
@@ -0,0 +1,142 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+
+use anyhow::Result;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::revoked_tokens::AddRevokedTokenEntity,
+        repositories::token_revocation::TokenRevocationRepository,
+    },
+    infrastructure::jwt::jwt_model::Claims,
+};
+
+/// How often revocations written by other server instances are picked up.
+const SYNC_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Default)]
+struct RevocationState {
+    tokens: HashMap<Uuid, NaiveDateTime>,
+    session_cutoffs: HashMap<i32, NaiveDateTime>,
+}
+
+/// Revoked token ids and per-brawler session cutoffs, persisted through the
+/// repository and mirrored in memory so that checking a token never touches
+/// the database.
+pub struct RevocationList {
+    token_revocation_repository: Arc<dyn TokenRevocationRepository + Send + Sync>,
+    state: RwLock<RevocationState>,
+}
+
+impl RevocationList {
+    pub async fn load(
+        token_revocation_repository: Arc<dyn TokenRevocationRepository + Send + Sync>,
+    ) -> Result<Self> {
+        let revocation_list = Self {
+            token_revocation_repository,
+            state: RwLock::new(RevocationState::default()),
+        };
+        revocation_list.sync().await?;
+
+        Ok(revocation_list)
+    }
+
+    pub fn spawn_sync(self: &Arc<Self>) {
+        let revocation_list = Arc::clone(self);
+
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(SYNC_INTERVAL).await;
+
+                if let Err(e) = revocation_list.token_revocation_repository.remove_expired().await {
+                    warn!("Failed to remove expired token revocations: {}", e);
+                }
+                if let Err(e) = revocation_list.sync().await {
+                    warn!("Failed to sync token revocations: {}", e);
+                }
+            }
+        });
+    }
+
+    pub async fn sync(&self) -> Result<()> {
+        let tokens = self
+            .token_revocation_repository
+            .active_revocations()
+            .await?
+            .into_iter()
+            .map(|revoked| (revoked.jti, revoked.expires_at))
+            .collect();
+        let session_cutoffs = self
+            .token_revocation_repository
+            .session_cutoffs()
+            .await?
+            .into_iter()
+            .collect();
+
+        let mut state = self.state.write().unwrap_or_else(|e| e.into_inner());
+        *state = RevocationState {
+            tokens,
+            session_cutoffs,
+        };
+
+        Ok(())
+    }
+
+    pub fn is_revoked(&self, claims: &Claims) -> bool {
+        let Ok(jti) = Uuid::parse_str(&claims.jti) else {
+            return true;
+        };
+        let Ok(brawler_id) = claims.sub.parse::<i32>() else {
+            return true;
+        };
+
+        let state = self.state.read().unwrap_or_else(|e| e.into_inner());
+
+        if state.tokens.contains_key(&jti) {
+            return true;
+        }
+
+        match state.session_cutoffs.get(&brawler_id) {
+            Some(cutoff) => {
+                (claims.iat * 1_000_000.0).round() as i64 <= cutoff.and_utc().timestamp_micros()
+            }
+            None => false,
+        }
+    }
+
+    pub async fn revoke(&self, claims: &Claims) -> Result<()> {
+        let jti = Uuid::parse_str(&claims.jti)?;
+        let brawler_id = claims.sub.parse::<i32>()?;
+        let expires_at = DateTime::<Utc>::from_timestamp(claims.exp as i64, 0)
+            .ok_or_else(|| anyhow::anyhow!("Invalid token expiry"))?
+            .naive_utc();
+
+        self.token_revocation_repository
+            .revoke(AddRevokedTokenEntity {
+                jti,
+                brawler_id,
+                expires_at,
+            })
+            .await?;
+
+        let mut state = self.state.write().unwrap_or_else(|e| e.into_inner());
+        state.tokens.insert(jti, expires_at);
+
+        Ok(())
+    }
+
+    pub async fn revoke_all(&self, brawler_id: i32) -> Result<()> {
+        let cutoff = self.token_revocation_repository.revoke_all(brawler_id).await?;
+
+        let mut state = self.state.write().unwrap_or_else(|e| e.into_inner());
+        state.session_cutoffs.insert(brawler_id, cutoff);
+
+        Ok(())
+    }
+}
@@ -2,4 +2,8 @@ pub mod database;
 pub mod http;
 pub mod argon2;
 pub mod jwt;
-pub mod cloudinary;
\ No newline at end of file
+pub mod cloudinary;
+pub mod image_processing;
+pub mod image_storage;
+#[cfg(feature = "testing")]
+pub mod in_memory;
\ No newline at end of file
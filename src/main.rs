@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use server::{config::config_loader::load, infrastructure::{database::postgresql_connection, http::http_serv::start}};
+use server::{config::{config_loader::{get_stage, load}, stage::Stage}, infrastructure::{database::postgresql_connection, http::http_serv::start}};
 use tracing::{Level, info, error};
 
 
@@ -21,7 +21,7 @@ async fn main() {
 
     info!("YESSSSS");         
 
-    let postgres_pool = match postgresql_connection::establish_connection(&dotenvy_env.database.url)
+    let postgres_pool = match postgresql_connection::establish_connection(&dotenvy_env.database)
     {
         Ok(pool) => pool,
         Err(e) => {
@@ -32,6 +32,22 @@ async fn main() {
 
     info!("Connect to the PostgreSQL database successfully.");
 
+    // `server --migrate` only brings the schema up to date, local runs do it on every start.
+    let migrate_only = std::env::args().any(|arg| arg == "--migrate");
+    if migrate_only || get_stage() == Stage::Local {
+        match postgresql_connection::run_migrations(&postgres_pool, dotenvy_env.max_crew_per_mission) {
+            Ok(applied) if applied.is_empty() => info!("Database schema is up to date."),
+            Ok(applied) => info!("Applied migrations: {}", applied.join(", ")),
+            Err(e) => {
+                error!("Failed to migrate the database: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+    if migrate_only {
+        return;
+    }
+
 // Use MissionEntity from server::domain::entities::missions; no local definition needed here.
 
 
@@ -0,0 +1,172 @@
+mod common;
+
+use axum::http::{Method, StatusCode};
+use serde_json::json;
+
+use common::{PASSWORD, TestApp};
+
+#[tokio::test]
+async fn registered_brawlers_can_log_in() {
+    let app = TestApp::new().await;
+    let registered = app.register("alice").await;
+
+    let logged_in = app.login("alice").await;
+
+    assert_eq!(logged_in.id, registered.id);
+    assert_ne!(logged_in.refresh_token, registered.refresh_token);
+}
+
+#[tokio::test]
+async fn usernames_are_unique() {
+    let app = TestApp::new().await;
+    app.register("alice").await;
+
+    let response = app
+        .request(
+            Method::POST,
+            "/brawler/register",
+            None,
+            Some(json!({ "username": "alice", "password": PASSWORD, "display_name": "Alice" })),
+        )
+        .await;
+
+    assert_eq!(response.status, StatusCode::CONFLICT);
+    assert_eq!(response.body["message"], "Username is already taken!");
+}
+
+#[tokio::test]
+async fn wrong_passwords_and_unknown_usernames_are_rejected_alike() {
+    let app = TestApp::new().await;
+    app.register("alice").await;
+
+    for (username, password) in [("alice", "wrong"), ("nobody", PASSWORD)] {
+        let response = app
+            .request(
+                Method::POST,
+                "/auth/login",
+                None,
+                Some(json!({ "username": username, "password": password })),
+            )
+            .await;
+
+        assert_eq!(response.status, StatusCode::UNAUTHORIZED);
+        assert_eq!(response.body["message"], "Invalid username or password!");
+    }
+}
+
+#[tokio::test]
+async fn protected_routes_need_a_valid_token() {
+    let app = TestApp::new().await;
+
+    let missing = app.request(Method::GET, "/brawler/me", None, None).await;
+    assert_eq!(missing.status, StatusCode::UNAUTHORIZED);
+    assert_eq!(missing.code(), "UNAUTHORIZED");
+
+    let invalid = app
+        .request(Method::GET, "/brawler/me", Some("not-a-jwt"), None)
+        .await;
+    assert_eq!(invalid.status, StatusCode::UNAUTHORIZED);
+    assert_eq!(invalid.body["message"], "Invalid or expired token!");
+}
+
+#[tokio::test]
+async fn logged_out_tokens_are_revoked() {
+    let app = TestApp::new().await;
+    let alice = app.register("alice").await;
+
+    let logout = app
+        .request(
+            Method::POST,
+            "/auth/logout",
+            Some(&alice.access_token),
+            Some(json!({ "refresh_token": alice.refresh_token })),
+        )
+        .await;
+    assert_eq!(logout.status, StatusCode::NO_CONTENT);
+
+    let me = app
+        .request(Method::GET, "/brawler/me", Some(&alice.access_token), None)
+        .await;
+    assert_eq!(me.status, StatusCode::UNAUTHORIZED);
+    assert_eq!(me.body["message"], "Token has been revoked!");
+
+    let refresh = app
+        .request(
+            Method::POST,
+            "/auth/refresh",
+            None,
+            Some(json!({ "refresh_token": alice.refresh_token })),
+        )
+        .await;
+    assert_eq!(refresh.status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn logging_out_everywhere_spares_later_logins() {
+    let app = TestApp::new().await;
+    let alice = app.register("alice").await;
+
+    let logout_all = app
+        .request(Method::POST, "/auth/logout-all", Some(&alice.access_token), None)
+        .await;
+    assert_eq!(logout_all.status, StatusCode::NO_CONTENT, "{}", logout_all.body);
+
+    // Usually within the same second as the cutoff.
+    let logged_in = app.login("alice").await;
+
+    let old = app
+        .request(Method::GET, "/brawler/me", Some(&alice.access_token), None)
+        .await;
+    assert_eq!(old.status, StatusCode::UNAUTHORIZED);
+    let fresh = app
+        .request(Method::GET, "/brawler/me", Some(&logged_in.access_token), None)
+        .await;
+    assert_eq!(fresh.status, StatusCode::OK, "{}", fresh.body);
+}
+
+#[tokio::test]
+async fn reusing_a_refresh_token_revokes_its_family() {
+    let app = TestApp::new().await;
+    let alice = app.register("alice").await;
+    let refresh = |refresh_token: String| {
+        app.request(
+            Method::POST,
+            "/auth/refresh",
+            None,
+            Some(json!({ "refresh_token": refresh_token })),
+        )
+    };
+
+    let rotated = refresh(alice.refresh_token.clone()).await;
+    assert_eq!(rotated.status, StatusCode::OK);
+    let rotated_token = rotated.body["refresh_token"].as_str().unwrap().to_string();
+
+    let reused = refresh(alice.refresh_token.clone()).await;
+    assert_eq!(reused.status, StatusCode::UNAUTHORIZED);
+    assert_eq!(
+        reused.body["message"],
+        "Refresh token reuse detected, please log in again!"
+    );
+
+    let after_reuse = refresh(rotated_token).await;
+    assert_eq!(after_reuse.status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn admin_routes_are_forbidden_to_brawlers() {
+    let app = TestApp::new().await;
+    let alice = app.register("alice").await;
+    let bob = app.register("bob").await;
+
+    let response = app
+        .request(
+            Method::PATCH,
+            &format!("/admin/brawlers/{}/role", bob.id),
+            Some(&alice.access_token),
+            Some(json!({ "role": "admin" })),
+        )
+        .await;
+
+    assert_eq!(response.status, StatusCode::FORBIDDEN);
+    assert_eq!(response.code(), "FORBIDDEN");
+}
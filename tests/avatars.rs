@@ -0,0 +1,440 @@
+mod common;
+
+use std::{io::Cursor, sync::Arc, time::Duration};
+
+use axum::http::{Method, StatusCode};
+use image::{DynamicImage, ImageFormat, Rgb, RgbImage, RgbaImage};
+use serde_json::json;
+use server::infrastructure::{
+    http::repositories::Repositories, image_storage::cleanup_queue::ImageCleanupQueue,
+};
+
+use common::{MAX_AVATAR_SIZE_KB, Response, TestApp, TestBrawler};
+
+/// A 1x1 PNG.
+const PNG: &str = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNk+M9QDwADhgGAWjR9awAAAABJRU5ErkJggg==";
+const GPS_MARKER: &[u8] = b"GPS 52.3676N 4.9041E";
+
+const RED: Rgb<u8> = Rgb([255, 0, 0]);
+const BLUE: Rgb<u8> = Rgb([0, 0, 255]);
+
+/// Left half red, right half blue.
+fn halves(width: u32, height: u32) -> DynamicImage {
+    DynamicImage::ImageRgb8(RgbImage::from_fn(width, height, |x, _| {
+        if x < width / 2 { RED } else { BLUE }
+    }))
+}
+
+fn encode(image: &DynamicImage, format: ImageFormat) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    image.write_to(&mut Cursor::new(&mut bytes), format).unwrap();
+    bytes
+}
+
+/// Inserts an EXIF segment with the orientation and a GPS-like description
+/// right after the JFIF header of a JPEG.
+fn with_exif(jpeg: Vec<u8>, orientation: u16) -> Vec<u8> {
+    let mut tiff = b"MM\x00\x2a\x00\x00\x00\x08".to_vec();
+    tiff.extend_from_slice(&2u16.to_be_bytes());
+    // ImageDescription, ASCII, pointing past the IFD.
+    tiff.extend_from_slice(&0x010eu16.to_be_bytes());
+    tiff.extend_from_slice(&2u16.to_be_bytes());
+    tiff.extend_from_slice(&(GPS_MARKER.len() as u32 + 1).to_be_bytes());
+    tiff.extend_from_slice(&38u32.to_be_bytes());
+    // Orientation, SHORT.
+    tiff.extend_from_slice(&0x0112u16.to_be_bytes());
+    tiff.extend_from_slice(&3u16.to_be_bytes());
+    tiff.extend_from_slice(&1u32.to_be_bytes());
+    tiff.extend_from_slice(&orientation.to_be_bytes());
+    tiff.extend_from_slice(&[0, 0]);
+    tiff.extend_from_slice(&0u32.to_be_bytes());
+    tiff.extend_from_slice(GPS_MARKER);
+    tiff.push(0);
+
+    let mut segment = vec![0xff, 0xe1];
+    segment.extend_from_slice(&(tiff.len() as u16 + 8).to_be_bytes());
+    segment.extend_from_slice(b"Exif\x00\x00");
+    segment.extend_from_slice(&tiff);
+
+    let app0_end = 4 + u16::from_be_bytes([jpeg[4], jpeg[5]]) as usize;
+    [&jpeg[..app0_end], &segment, &jpeg[app0_end..]].concat()
+}
+
+fn stored_image(app: &TestApp, public_id: &str) -> (Vec<u8>, RgbaImage) {
+    let bytes = app.images.images()[public_id].clone();
+    let image = image::load_from_memory_with_format(&bytes, ImageFormat::Png)
+        .unwrap()
+        .to_rgba8();
+
+    (bytes, image)
+}
+
+/// The `public_id` of one size in an upload response.
+fn size_public_id(response: &Response, size: u32) -> String {
+    response.body["sizes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|entry| entry["size"] == size)
+        .unwrap()["public_id"]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
+async fn upload_png(app: &TestApp, brawler: &TestBrawler) -> Response {
+    let png = encode(&halves(8, 8), ImageFormat::Png);
+    let response = app
+        .upload_file("/brawler/avatar/file", &brawler.access_token, "avatar", &png)
+        .await;
+    assert_eq!(response.status, StatusCode::CREATED, "{}", response.body);
+
+    response
+}
+
+fn is_red(image: &RgbaImage, x: u32, y: u32) -> bool {
+    let [r, _, b, _] = image.get_pixel(x, y).0;
+    r > 200 && b < 60
+}
+
+fn is_blue(image: &RgbaImage, x: u32, y: u32) -> bool {
+    let [r, _, b, _] = image.get_pixel(x, y).0;
+    b > 200 && r < 60
+}
+
+#[tokio::test]
+async fn uploaded_avatars_are_stored_in_every_size_and_shown_on_the_profile() {
+    let app = TestApp::new().await;
+    let alice = app.register("alice").await;
+
+    let response = app
+        .request(
+            Method::POST,
+            "/brawler/avatar",
+            Some(&alice.access_token),
+            Some(json!({ "base64_string": PNG })),
+        )
+        .await;
+
+    assert_eq!(response.status, StatusCode::CREATED, "{}", response.body);
+    assert_eq!(response.body["public_id"], size_public_id(&response, 256));
+    assert!(
+        response.body["public_id"]
+            .as_str()
+            .unwrap()
+            .starts_with(&format!("brawlers_avatar/{}_", alice.id))
+    );
+    let sizes = response.body["sizes"].as_array().unwrap();
+    assert_eq!(sizes.len(), 3);
+    for (entry, size) in sizes.iter().zip([64, 128, 256]) {
+        assert_eq!(entry["size"], size);
+        let (_, image) = stored_image(&app, entry["public_id"].as_str().unwrap());
+        assert_eq!(image.dimensions(), (size, size));
+    }
+
+    let avatar_sizes = sizes
+        .iter()
+        .map(|entry| json!({ "size": entry["size"], "url": entry["url"] }))
+        .collect::<Vec<_>>();
+    let mission_id = app.add_mission(&alice, json!({ "name": "Heist" })).await;
+    let me = app
+        .request(Method::GET, "/brawler/me", Some(&alice.access_token), None)
+        .await;
+    let profile = app
+        .request(Method::GET, &format!("/brawler/{}", alice.id), None, None)
+        .await;
+    let crew = app
+        .request(Method::GET, &format!("/view/crew/{}", mission_id), None, None)
+        .await;
+    for (uri, body) in [
+        ("/brawler/me", &me.body),
+        ("/brawler/{id}", &profile.body),
+        ("/view/crew/{id}", &crew.body[0]),
+    ] {
+        assert_eq!(body["avatar_url"], response.body["url"], "{}", uri);
+        assert_eq!(body["avatar_sizes"], json!(avatar_sizes), "{}", uri);
+    }
+}
+
+#[tokio::test]
+async fn avatars_must_be_images() {
+    let app = TestApp::new().await;
+    let alice = app.register("alice").await;
+
+    let response = app
+        .request(
+            Method::POST,
+            "/brawler/avatar",
+            Some(&alice.access_token),
+            Some(json!({ "base64_string": "bm90IGFuIGltYWdl" })),
+        )
+        .await;
+
+    assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(app.images.images().is_empty());
+}
+
+#[tokio::test]
+async fn avatars_are_cropped_to_the_center_square() {
+    let app = TestApp::new().await;
+    let alice = app.register("alice").await;
+    // A 50px red border left and right around a 100px square split in halves.
+    let mut wide = RgbImage::from_pixel(200, 100, RED);
+    for (x, _, pixel) in wide.enumerate_pixels_mut() {
+        if (100..150).contains(&x) {
+            *pixel = BLUE;
+        }
+    }
+    let png = encode(&DynamicImage::ImageRgb8(wide), ImageFormat::Png);
+
+    let response = app
+        .upload_file("/brawler/avatar/file", &alice.access_token, "avatar", &png)
+        .await;
+
+    assert_eq!(response.status, StatusCode::CREATED, "{}", response.body);
+    let (_, image) = stored_image(&app, &size_public_id(&response, 64));
+    assert!(is_red(&image, 8, 32));
+    assert!(is_blue(&image, 56, 32));
+}
+
+#[tokio::test]
+async fn jpeg_avatars_are_turned_upright_and_lose_their_exif_data() {
+    let app = TestApp::new().await;
+    let alice = app.register("alice").await;
+    // Orientation 6 asks viewers to rotate 90 degrees clockwise, which puts the
+    // red left half on top.
+    let jpeg = with_exif(encode(&halves(200, 100), ImageFormat::Jpeg), 6);
+
+    let response = app
+        .upload_file("/brawler/avatar/file", &alice.access_token, "avatar", &jpeg)
+        .await;
+
+    assert_eq!(response.status, StatusCode::CREATED, "{}", response.body);
+    for size in [64, 128, 256] {
+        let (bytes, image) = stored_image(&app, &size_public_id(&response, size));
+        assert!(is_red(&image, size / 2, size / 8), "{}px", size);
+        assert!(is_blue(&image, size / 2, size - size / 8), "{}px", size);
+        assert!(!bytes.windows(4).any(|window| window == b"Exif"));
+        assert!(!bytes.windows(GPS_MARKER.len()).any(|window| window == GPS_MARKER));
+    }
+}
+
+#[tokio::test]
+async fn webp_avatars_are_accepted() {
+    let app = TestApp::new().await;
+    let alice = app.register("alice").await;
+    let webp = encode(&halves(32, 32), ImageFormat::WebP);
+
+    let response = app
+        .upload_file("/brawler/avatar/file", &alice.access_token, "avatar", &webp)
+        .await;
+
+    assert_eq!(response.status, StatusCode::CREATED, "{}", response.body);
+    let (_, image) = stored_image(&app, &size_public_id(&response, 256));
+    assert!(is_red(&image, 32, 128));
+    assert!(is_blue(&image, 224, 128));
+}
+
+#[tokio::test]
+async fn corrupt_images_are_rejected() {
+    let app = TestApp::new().await;
+    let alice = app.register("alice").await;
+    let mut png = encode(&halves(32, 32), ImageFormat::Png);
+    png.truncate(40);
+
+    let response = app
+        .upload_file("/brawler/avatar/file", &alice.access_token, "avatar", &png)
+        .await;
+
+    assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(app.images.images().is_empty());
+}
+
+#[tokio::test]
+async fn avatars_over_4096_pixels_on_a_side_are_rejected() {
+    let app = TestApp::new().await;
+    let alice = app.register("alice").await;
+    let png = encode(&halves(4097, 1), ImageFormat::Png);
+
+    let response = app
+        .upload_file("/brawler/avatar/file", &alice.access_token, "avatar", &png)
+        .await;
+
+    assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY, "{}", response.body);
+    assert!(app.images.images().is_empty());
+}
+
+#[tokio::test]
+async fn multipart_avatars_over_the_size_cap_are_rejected() {
+    let app = TestApp::new().await;
+    let alice = app.register("alice").await;
+    let mut bytes = encode(&halves(1, 1), ImageFormat::Png);
+    bytes.resize(MAX_AVATAR_SIZE_KB * 1024 + 1, 0);
+
+    let response = app
+        .upload_file("/brawler/avatar/file", &alice.access_token, "avatar", &bytes)
+        .await;
+
+    assert_eq!(response.status, StatusCode::PAYLOAD_TOO_LARGE);
+    assert_eq!(response.code(), "PAYLOAD_TOO_LARGE");
+    assert!(app.images.images().is_empty());
+}
+
+#[tokio::test]
+async fn multipart_avatars_are_sniffed_and_need_the_avatar_field() {
+    let app = TestApp::new().await;
+    let alice = app.register("alice").await;
+    let png = encode(&halves(1, 1), ImageFormat::Png);
+
+    let not_an_image = app
+        .upload_file(
+            "/brawler/avatar/file",
+            &alice.access_token,
+            "avatar",
+            b"GIF89a, or anything else that is not a PNG, JPEG or WebP",
+        )
+        .await;
+    let wrong_field = app
+        .upload_file("/brawler/avatar/file", &alice.access_token, "file", &png)
+        .await;
+
+    assert_eq!(not_an_image.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(wrong_field.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(app.images.images().is_empty());
+}
+
+#[tokio::test]
+async fn the_size_cap_also_applies_to_base64_avatars() {
+    let app = TestApp::new().await;
+    let alice = app.register("alice").await;
+    // Base64 of a PNG signature followed by zeros, decoding to just over the cap.
+    let oversized = format!("iVBORw0KGgo{}", "A".repeat(MAX_AVATAR_SIZE_KB * 1024 * 4 / 3 + 4));
+
+    let response = app
+        .request(
+            Method::POST,
+            "/brawler/avatar",
+            Some(&alice.access_token),
+            Some(json!({ "base64_string": oversized })),
+        )
+        .await;
+
+    assert_eq!(response.status, StatusCode::PAYLOAD_TOO_LARGE);
+}
+
+#[tokio::test]
+async fn replacing_an_avatar_deletes_the_previous_images() {
+    let app = TestApp::new().await;
+    let alice = app.register("alice").await;
+    let first = upload_png(&app, &alice).await;
+
+    let second = upload_png(&app, &alice).await;
+    // The request only queues the deletions, the cleanup worker runs them.
+    assert_eq!(app.images.images().len(), 6);
+    app.state.image_cleanup.process_queued().await;
+
+    let mut stored = app.images.images().keys().cloned().collect::<Vec<_>>();
+    stored.sort();
+    let mut expected = [64, 128, 256].map(|size| size_public_id(&second, size)).to_vec();
+    expected.sort();
+    assert_eq!(stored, expected);
+    assert_ne!(first.body["public_id"], second.body["public_id"]);
+}
+
+#[tokio::test]
+async fn removed_avatars_are_cleared_and_deleted() {
+    let app = TestApp::new().await;
+    let alice = app.register("alice").await;
+    upload_png(&app, &alice).await;
+
+    let response = app
+        .request(Method::DELETE, "/brawler/avatar", Some(&alice.access_token), None)
+        .await;
+
+    assert_eq!(response.status, StatusCode::NO_CONTENT);
+    assert_eq!(app.images.images().len(), 3);
+    app.state.image_cleanup.process_queued().await;
+    assert!(app.images.images().is_empty());
+    assert!(app.state.image_cleanup.pending().await.unwrap().is_empty());
+    let me = app
+        .request(Method::GET, "/brawler/me", Some(&alice.access_token), None)
+        .await;
+    assert!(me.body["avatar_url"].is_null());
+    assert_eq!(me.body["avatar_sizes"], json!([]));
+
+    let again = app
+        .request(Method::DELETE, "/brawler/avatar", Some(&alice.access_token), None)
+        .await;
+    assert_eq!(again.status, StatusCode::NO_CONTENT);
+}
+
+#[tokio::test]
+async fn failed_deletions_are_retried_from_the_cleanup_queue() {
+    let app = TestApp::new().await;
+    let alice = app.register("alice").await;
+    let uploaded = upload_png(&app, &alice).await;
+    app.images.set_fail_deletes(true);
+
+    let response = app
+        .request(Method::DELETE, "/brawler/avatar", Some(&alice.access_token), None)
+        .await;
+
+    assert_eq!(response.status, StatusCode::NO_CONTENT);
+    app.state.image_cleanup.process_queued().await;
+    assert_eq!(app.images.images().len(), 3);
+    let mut pending = app.state.image_cleanup.pending().await.unwrap();
+    pending.sort();
+    let mut expected = [64, 128, 256].map(|size| size_public_id(&uploaded, size)).to_vec();
+    expected.sort();
+    assert_eq!(pending, expected);
+
+    app.state.image_cleanup.retry_pending().await;
+    assert_eq!(app.state.image_cleanup.pending().await.unwrap().len(), 3);
+
+    app.images.set_fail_deletes(false);
+    app.state.image_cleanup.retry_pending().await;
+    assert!(app.state.image_cleanup.pending().await.unwrap().is_empty());
+    assert!(app.images.images().is_empty());
+}
+
+#[tokio::test]
+async fn the_cleanup_worker_deletes_what_requests_enqueue() {
+    let app = TestApp::new().await;
+    let alice = app.register("alice").await;
+    upload_png(&app, &alice).await;
+    app.state.image_cleanup.spawn_worker();
+
+    app.request(Method::DELETE, "/brawler/avatar", Some(&alice.access_token), None)
+        .await;
+
+    tokio::time::timeout(Duration::from_secs(5), async {
+        while !app.images.images().is_empty() {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+    })
+    .await
+    .expect("the worker left the images behind");
+}
+
+#[tokio::test]
+async fn pending_deletions_outlive_the_cleanup_queue() {
+    let app = TestApp::new().await;
+    let alice = app.register("alice").await;
+    upload_png(&app, &alice).await;
+    app.images.set_fail_deletes(true);
+    app.request(Method::DELETE, "/brawler/avatar", Some(&alice.access_token), None)
+        .await;
+    app.state.image_cleanup.process_queued().await;
+
+    // What a restarted server would start with.
+    let restarted = ImageCleanupQueue::new(
+        Arc::clone(&app.images) as _,
+        app.state.repositories.pending_image_deletion(),
+    );
+    assert_eq!(restarted.pending().await.unwrap().len(), 3);
+
+    app.images.set_fail_deletes(false);
+    restarted.retry_pending().await;
+    assert!(restarted.pending().await.unwrap().is_empty());
+    assert!(app.images.images().is_empty());
+}
@@ -0,0 +1,73 @@
+mod common;
+
+use axum::http::{Method, StatusCode};
+use serde_json::json;
+
+use common::{PASSWORD, TestApp};
+
+#[tokio::test]
+async fn leaderboard_pages_are_validated() {
+    let app = TestApp::new().await;
+    app.register("alice").await;
+
+    let first = app
+        .request(Method::GET, "/brawler/leaderboard?page=1", None, None)
+        .await;
+    assert_eq!(first.status, StatusCode::OK, "{}", first.body);
+    assert_eq!(first.body["brawlers"].as_array().unwrap().len(), 1);
+
+    for page in ["0", "-1", &i64::MAX.to_string()] {
+        let response = app
+            .request(
+                Method::GET,
+                &format!("/brawler/leaderboard?page={}&page_size=100", page),
+                None,
+                None,
+            )
+            .await;
+
+        assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY, "page {}", page);
+        assert_eq!(response.code(), "VALIDATION_FAILED");
+    }
+}
+
+#[tokio::test]
+async fn changing_the_password_signs_out_every_session() {
+    let app = TestApp::new().await;
+    let alice = app.register("alice").await;
+    let other_session = app.login("alice").await;
+
+    let changed = app
+        .request(
+            Method::POST,
+            "/brawler/me/password",
+            Some(&alice.access_token),
+            Some(json!({ "current_password": PASSWORD, "new_password": "a new secret" })),
+        )
+        .await;
+    assert_eq!(changed.status, StatusCode::OK, "{}", changed.body);
+
+    for access_token in [&alice.access_token, &other_session.access_token] {
+        let me = app
+            .request(Method::GET, "/brawler/me", Some(access_token), None)
+            .await;
+        assert_eq!(me.status, StatusCode::UNAUTHORIZED);
+        assert_eq!(me.body["message"], "Token has been revoked!");
+    }
+
+    let refresh = app
+        .request(
+            Method::POST,
+            "/auth/refresh",
+            None,
+            Some(json!({ "refresh_token": other_session.refresh_token })),
+        )
+        .await;
+    assert_eq!(refresh.status, StatusCode::UNAUTHORIZED);
+
+    let new_access_token = changed.body["access_token"].as_str().unwrap();
+    let me = app
+        .request(Method::GET, "/brawler/me", Some(new_access_token), None)
+        .await;
+    assert_eq!(me.status, StatusCode::OK, "{}", me.body);
+}
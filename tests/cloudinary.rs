@@ -0,0 +1,333 @@
+use std::{
+    collections::{BTreeMap, VecDeque},
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use axum::{
+    Json, Router,
+    extract::{Multipart, Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::post,
+};
+use serde_json::{Value, json};
+use sha1::{Digest, Sha1};
+use tokio::net::TcpListener;
+
+use server::{
+    config::config_model::CloudinaryEnv,
+    domain::{
+        errors::DomainError,
+        value_objects::{base64_image::Base64Image, uploaded_image::UploadImageOptions},
+    },
+    infrastructure::cloudinary::{CloudinaryClient, cloudinary_error::CloudinaryError, signature},
+};
+
+const CLOUD_NAME: &str = "demo";
+const API_KEY: &str = "1234";
+const API_SECRET: &str = "shh";
+
+/// A 1x1 PNG.
+const PNG: &str = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNk+M9QDwADhgGAWjR9awAAAABJRU5ErkJggg==";
+
+/// What the stand-in answers with once the signature checks out.
+enum Reply {
+    Json(StatusCode, Value),
+    Text(StatusCode, &'static str),
+    Delay(Duration),
+}
+
+#[derive(Debug, Clone)]
+struct Received {
+    action: String,
+    fields: BTreeMap<String, String>,
+}
+
+#[derive(Default)]
+struct MockState {
+    replies: Mutex<VecDeque<Reply>>,
+    received: Mutex<Vec<Received>>,
+}
+
+/// Local stand-in for the Cloudinary upload API. Every request must carry a
+/// valid signature; scripted replies are used in order, after which uploads
+/// and destroys succeed.
+struct MockCloudinary {
+    state: Arc<MockState>,
+    base_url: String,
+}
+
+impl MockCloudinary {
+    async fn start() -> Self {
+        let state = Arc::new(MockState::default());
+        let router = Router::new()
+            .route("/v1_1/{cloud_name}/image/{action}", post(handle))
+            .with_state(Arc::clone(&state));
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+        Self { state, base_url }
+    }
+
+    fn reply(&self, reply: Reply) {
+        self.state.replies.lock().unwrap().push_back(reply);
+    }
+
+    fn received(&self) -> Vec<Received> {
+        self.state.received.lock().unwrap().clone()
+    }
+
+    fn client(&self, max_retries: u32) -> CloudinaryClient {
+        CloudinaryClient::new(CloudinaryEnv {
+            cloud_name: CLOUD_NAME.to_string(),
+            api_key: API_KEY.to_string(),
+            api_secret: API_SECRET.to_string(),
+            base_url: self.base_url.clone(),
+            timeout: 1,
+            max_retries,
+        })
+        .unwrap()
+    }
+}
+
+async fn handle(
+    State(state): State<Arc<MockState>>,
+    Path((cloud_name, action)): Path<(String, String)>,
+    mut multipart: Multipart,
+) -> Response {
+    let mut fields = BTreeMap::new();
+    while let Some(field) = multipart.next_field().await.unwrap() {
+        let name = field.name().unwrap().to_string();
+        fields.insert(name, field.text().await.unwrap());
+    }
+    state.received.lock().unwrap().push(Received {
+        action: action.clone(),
+        fields: fields.clone(),
+    });
+
+    if cloud_name != CLOUD_NAME || fields.get("api_key").map(String::as_str) != Some(API_KEY) {
+        return error(StatusCode::UNAUTHORIZED, "Unknown API key");
+    }
+    if fields.get("signature") != Some(&expected_signature(&fields)) {
+        return error(StatusCode::UNAUTHORIZED, "Invalid Signature");
+    }
+
+    let reply = state.replies.lock().unwrap().pop_front();
+    match reply {
+        Some(Reply::Json(status, body)) => (status, Json(body)).into_response(),
+        Some(Reply::Text(status, body)) => (status, body).into_response(),
+        Some(Reply::Delay(delay)) => {
+            tokio::time::sleep(delay).await;
+            StatusCode::OK.into_response()
+        }
+        None if action == "upload" => {
+            let public_id = fields.get("public_id").cloned().unwrap_or_default();
+            let public_id = match fields.get("folder") {
+                Some(folder) => format!("{}/{}", folder, public_id),
+                None => public_id,
+            };
+            Json(json!({
+                "public_id": public_id,
+                "url": format!("http://res.cloudinary.com/{}/{}.png", CLOUD_NAME, public_id),
+                "secure_url": format!("https://res.cloudinary.com/{}/{}.png", CLOUD_NAME, public_id),
+            }))
+            .into_response()
+        }
+        None => Json(json!({ "result": "ok" })).into_response(),
+    }
+}
+
+/// Cloudinary's scheme: every parameter except `file`, `resource_type`,
+/// `api_key` and the signature itself, sorted, as `k=v` joined with `&`,
+/// followed by the secret and hashed with SHA-1.
+fn expected_signature(fields: &BTreeMap<String, String>) -> String {
+    let string_to_sign = fields
+        .iter()
+        .filter(|(key, _)| {
+            !matches!(
+                key.as_str(),
+                "file" | "resource_type" | "api_key" | "signature"
+            )
+        })
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect::<Vec<_>>()
+        .join("&");
+
+    format!("{:x}", Sha1::digest(format!("{}{}", string_to_sign, API_SECRET)))
+}
+
+fn error(status: StatusCode, message: &str) -> Response {
+    (status, Json(json!({ "error": { "message": message } }))).into_response()
+}
+
+fn image() -> Base64Image {
+    Base64Image::new(PNG.to_string()).unwrap()
+}
+
+fn options() -> UploadImageOptions {
+    UploadImageOptions {
+        folder: Some("avatars".to_string()),
+        public_id: Some("7_256".to_string()),
+        transformation: Some("c_fill,w_256,h_256".to_string()),
+    }
+}
+
+/// The examples of Cloudinary's "Generating authentication signatures" guide.
+#[test]
+fn signatures_match_the_published_examples() {
+    let mut params = BTreeMap::new();
+    params.insert("public_id".to_string(), "sample_image".to_string());
+    assert_eq!(
+        signature(&params, 1315060510, "abcd"),
+        "b4ad47fb4e25c7bf5f92a20089f9db59bc302313"
+    );
+
+    params.insert(
+        "eager".to_string(),
+        "w_400,h_300,c_pad|w_260,h_200,c_crop".to_string(),
+    );
+    assert_eq!(
+        signature(&params, 1315060510, "abcd"),
+        "bfd09f95f331f558cbd1320e67aa8d488770583e"
+    );
+}
+
+#[tokio::test]
+async fn uploads_are_signed() {
+    let cloudinary = MockCloudinary::start().await;
+
+    let uploaded = cloudinary
+        .client(0)
+        .upload(image(), options())
+        .await
+        .unwrap();
+
+    assert_eq!(uploaded.public_id, "avatars/7_256");
+    assert_eq!(
+        uploaded.url,
+        "https://res.cloudinary.com/demo/avatars/7_256.png"
+    );
+
+    let received = cloudinary.received();
+    assert_eq!(received.len(), 1);
+    let fields = &received[0].fields;
+    assert_eq!(received[0].action, "upload");
+    assert_eq!(fields["folder"], "avatars");
+    assert_eq!(fields["transformation"], "c_fill,w_256,h_256");
+    assert!(fields["file"].starts_with("data:image/png;base64,"));
+    let timestamp: i64 = fields["timestamp"].parse().unwrap();
+    assert!((chrono::Utc::now().timestamp() - timestamp).abs() < 60);
+}
+
+#[tokio::test]
+async fn server_errors_are_retried() {
+    let cloudinary = MockCloudinary::start().await;
+    cloudinary.reply(Reply::Json(
+        StatusCode::INTERNAL_SERVER_ERROR,
+        json!({ "error": { "message": "General Error" } }),
+    ));
+    cloudinary.reply(Reply::Text(StatusCode::BAD_GATEWAY, "<html>Bad Gateway</html>"));
+
+    let uploaded = cloudinary
+        .client(2)
+        .upload(image(), options())
+        .await
+        .unwrap();
+
+    assert_eq!(uploaded.public_id, "avatars/7_256");
+    assert_eq!(cloudinary.received().len(), 3);
+}
+
+#[tokio::test]
+async fn retries_give_up_after_max_retries() {
+    let cloudinary = MockCloudinary::start().await;
+    for _ in 0..3 {
+        cloudinary.reply(Reply::Text(StatusCode::SERVICE_UNAVAILABLE, "unavailable"));
+    }
+
+    let error = cloudinary
+        .client(1)
+        .upload(image(), options())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        CloudinaryError::UnexpectedResponse { status, .. } if status == StatusCode::SERVICE_UNAVAILABLE
+    ));
+    assert_eq!(cloudinary.received().len(), 2);
+}
+
+#[tokio::test]
+async fn error_payloads_are_decoded_and_not_retried() {
+    let cloudinary = MockCloudinary::start().await;
+    cloudinary.reply(Reply::Json(
+        StatusCode::BAD_REQUEST,
+        json!({ "error": { "message": "Invalid image file" } }),
+    ));
+
+    let error = cloudinary
+        .client(3)
+        .upload(image(), options())
+        .await
+        .unwrap_err();
+
+    match &error {
+        CloudinaryError::Api { status, message } => {
+            assert_eq!(*status, StatusCode::BAD_REQUEST);
+            assert_eq!(message, "Invalid image file");
+        }
+        other => panic!("expected an API error, got {:?}", other),
+    }
+    assert_eq!(cloudinary.received().len(), 1);
+
+    let domain_error = DomainError::from(error);
+    assert!(matches!(domain_error, DomainError::Upstream(_)));
+}
+
+#[tokio::test]
+async fn slow_answers_time_out() {
+    let cloudinary = MockCloudinary::start().await;
+    cloudinary.reply(Reply::Delay(Duration::from_secs(3)));
+
+    let error = cloudinary
+        .client(0)
+        .upload(image(), options())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(&error, CloudinaryError::Transport(e) if e.is_timeout()));
+}
+
+#[tokio::test]
+async fn destroy_is_signed_and_tolerates_missing_images() {
+    let cloudinary = MockCloudinary::start().await;
+    cloudinary.reply(Reply::Json(StatusCode::OK, json!({ "result": "not found" })));
+    let client = cloudinary.client(0);
+
+    client.destroy("avatars/7_256").await.unwrap();
+    client.destroy("avatars/7_128").await.unwrap();
+
+    let received = cloudinary.received();
+    assert_eq!(received.len(), 2);
+    assert_eq!(received[1].action, "destroy");
+    assert_eq!(received[1].fields["public_id"], "avatars/7_128");
+    assert_eq!(received[1].fields["invalidate"], "true");
+}
+
+#[tokio::test]
+async fn destroy_reports_unexpected_results() {
+    let cloudinary = MockCloudinary::start().await;
+    cloudinary.reply(Reply::Json(StatusCode::OK, json!({ "result": "error" })));
+
+    let error = cloudinary
+        .client(0)
+        .destroy("avatars/7_256")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, CloudinaryError::NotDestroyed { .. }));
+}
@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use diesel::{Connection, PgConnection, RunQueryDsl, sql_query};
+use server::{
+    config::config_model::Database,
+    infrastructure::database::postgresql_connection::{
+        PgPoolSquad, establish_connection, run_migrations,
+    },
+};
+use uuid::Uuid;
+
+use super::MAX_CREW_PER_MISSION;
+
+/// Server the throwaway databases are created on, e.g.
+/// `postgres://postgres@localhost/postgres`. The user needs `CREATEDB`.
+pub const TEST_DATABASE_URL: &str = "TEST_DATABASE_URL";
+
+/// A fresh database built from the embedded migrations, dropped again with
+/// the value.
+pub struct TestDatabase {
+    pub db_pool: Arc<PgPoolSquad>,
+    server_url: String,
+    name: String,
+}
+
+impl TestDatabase {
+    /// Panics when `TEST_DATABASE_URL` is not set, the tests using this are
+    /// `#[ignore]`d so that only `cargo test -- --ignored` gets here.
+    pub fn create() -> Self {
+        let server_url = std::env::var(TEST_DATABASE_URL)
+            .unwrap_or_else(|_| panic!("{} is not set", TEST_DATABASE_URL));
+        let name = format!("test_{}", Uuid::new_v4().simple());
+
+        let mut conn = PgConnection::establish(&server_url).unwrap();
+        sql_query(format!("CREATE DATABASE \"{}\"", name))
+            .execute(&mut conn)
+            .unwrap();
+
+        let db_pool = establish_connection(&Database {
+            url: with_database_name(&server_url, &name),
+            max_connections: 4,
+            min_idle: Some(0),
+            connection_timeout: 10,
+            idle_timeout: None,
+        })
+        .unwrap();
+        run_migrations(&db_pool, MAX_CREW_PER_MISSION).unwrap();
+
+        Self {
+            db_pool: Arc::new(db_pool),
+            server_url,
+            name,
+        }
+    }
+}
+
+impl Drop for TestDatabase {
+    fn drop(&mut self) {
+        // Repositories may still hold pooled connections, `FORCE` closes them.
+        let dropped = PgConnection::establish(&self.server_url)
+            .map_err(anyhow::Error::from)
+            .and_then(|mut conn| {
+                sql_query(format!("DROP DATABASE IF EXISTS \"{}\" WITH (FORCE)", self.name))
+                    .execute(&mut conn)?;
+                Ok(())
+            });
+        if let Err(e) = dropped {
+            eprintln!("Failed to drop test database {}: {}", self.name, e);
+        }
+    }
+}
+
+/// Swaps the database name in the path of a `postgres://` URL.
+fn with_database_name(server_url: &str, name: &str) -> String {
+    let (url, query) = match server_url.split_once('?') {
+        Some((url, query)) => (url, Some(query)),
+        None => (server_url, None),
+    };
+    let host_start = url.find("://").map_or(0, |index| index + 3);
+    let authority = match url[host_start..].find('/') {
+        Some(index) => &url[..host_start + index],
+        None => url,
+    };
+
+    match query {
+        Some(query) => format!("{}/{}?{}", authority, name, query),
+        None => format!("{}/{}", authority, name),
+    }
+}
@@ -0,0 +1,274 @@
+#![allow(dead_code)]
+
+pub mod database;
+
+use std::sync::Arc;
+
+use axum::{
+    Router,
+    body::{Body, to_bytes},
+    http::{Method, Request, StatusCode, header},
+};
+use serde_json::{Value, json};
+use server::{
+    config::config_model::{
+        Database, DotEnvyConfig, ImageStorageEnv, JwtEnv, JwtKeyEnv, LocalImageStorageEnv, Server,
+    },
+    domain::{repositories::brawlers::BrawlerRepository, value_objects::roles::Role},
+    infrastructure::{
+        http::{app_state::AppState, http_serv, repositories::Repositories},
+        in_memory::{InMemoryRepositories, image_storage::ImageStorageInMemory},
+    },
+};
+use tower::ServiceExt;
+
+pub const PASSWORD: &str = "correct horse battery staple";
+pub const MAX_CREW_PER_MISSION: u32 = 3;
+pub const MAX_AVATAR_SIZE_KB: usize = 64;
+
+pub struct Response {
+    pub status: StatusCode,
+    pub body: Value,
+}
+
+impl Response {
+    /// The `code` of an error body.
+    pub fn code(&self) -> &str {
+        self.body["code"].as_str().unwrap_or_default()
+    }
+}
+
+pub struct TestBrawler {
+    pub id: i32,
+    pub access_token: String,
+    pub refresh_token: String,
+}
+
+/// The `/api` router of [`http_serv::api_serve`] on top of in-memory repositories.
+pub struct TestApp {
+    pub router: Router,
+    pub state: AppState<InMemoryRepositories>,
+    pub images: Arc<ImageStorageInMemory>,
+}
+
+impl TestApp {
+    pub async fn new() -> Self {
+        let images = Arc::new(ImageStorageInMemory::new());
+        let state = AppState::new(
+            Arc::new(config()),
+            InMemoryRepositories::default(),
+            Arc::clone(&images) as _,
+        )
+        .await
+        .unwrap();
+        let router = http_serv::api_serve(&state);
+
+        Self {
+            router,
+            state,
+            images,
+        }
+    }
+
+    pub async fn request(
+        &self,
+        method: Method,
+        uri: &str,
+        access_token: Option<&str>,
+        body: Option<Value>,
+    ) -> Response {
+        let mut request = Request::builder().method(method).uri(uri);
+        if let Some(access_token) = access_token {
+            request = request.header(header::AUTHORIZATION, format!("Bearer {}", access_token));
+        }
+        let request = match body {
+            Some(body) => request
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from(body.to_string())),
+            None => request.body(Body::empty()),
+        }
+        .unwrap();
+
+        self.send(request).await
+    }
+
+    /// A `multipart/form-data` body with a single file field.
+    pub async fn upload_file(
+        &self,
+        uri: &str,
+        access_token: &str,
+        field_name: &str,
+        bytes: &[u8],
+    ) -> Response {
+        const BOUNDARY: &str = "test-boundary";
+
+        let mut body = format!(
+            "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"{field_name}\"; filename=\"upload\"\r\nContent-Type: application/octet-stream\r\n\r\n"
+        )
+        .into_bytes();
+        body.extend_from_slice(bytes);
+        body.extend_from_slice(format!("\r\n--{BOUNDARY}--\r\n").as_bytes());
+
+        let request = Request::builder()
+            .method(Method::POST)
+            .uri(uri)
+            .header(header::AUTHORIZATION, format!("Bearer {}", access_token))
+            .header(
+                header::CONTENT_TYPE,
+                format!("multipart/form-data; boundary={BOUNDARY}"),
+            )
+            .body(Body::from(body))
+            .unwrap();
+
+        self.send(request).await
+    }
+
+    pub async fn send(&self, request: Request<Body>) -> Response {
+        let response = self.router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body = serde_json::from_slice(&bytes)
+            .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&bytes).into_owned()));
+
+        Response { status, body }
+    }
+
+    pub async fn register(&self, username: &str) -> TestBrawler {
+        let response = self
+            .request(
+                Method::POST,
+                "/brawler/register",
+                None,
+                Some(json!({
+                    "username": username,
+                    "password": PASSWORD,
+                    "display_name": username,
+                })),
+            )
+            .await;
+        assert_eq!(response.status, StatusCode::CREATED, "{}", response.body);
+
+        self.brawler_from_passport(response.body).await
+    }
+
+    pub async fn login(&self, username: &str) -> TestBrawler {
+        let response = self
+            .request(
+                Method::POST,
+                "/auth/login",
+                None,
+                Some(json!({ "username": username, "password": PASSWORD })),
+            )
+            .await;
+        assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+
+        self.brawler_from_passport(response.body).await
+    }
+
+    /// Promotes the brawler and logs in again, roles are read into the token at login.
+    pub async fn register_admin(&self, username: &str) -> TestBrawler {
+        let brawler = self.register(username).await;
+        self.state
+            .repositories
+            .brawler()
+            .update_role(brawler.id, Role::Admin)
+            .await
+            .unwrap();
+
+        self.login(username).await
+    }
+
+    pub async fn add_mission(&self, chief: &TestBrawler, mission: Value) -> i32 {
+        let response = self
+            .request(
+                Method::POST,
+                "/mission-management",
+                Some(&chief.access_token),
+                Some(mission),
+            )
+            .await;
+        assert_eq!(response.status, StatusCode::CREATED, "{}", response.body);
+
+        response.body["mission_id"].as_i64().unwrap() as i32
+    }
+
+    pub async fn join(&self, brawler: &TestBrawler, mission_id: i32) -> Response {
+        self.request(
+            Method::POST,
+            &format!("/crew/join/{}", mission_id),
+            Some(&brawler.access_token),
+            None,
+        )
+        .await
+    }
+
+    pub async fn leave(&self, brawler: &TestBrawler, mission_id: i32) -> Response {
+        self.request(
+            Method::DELETE,
+            &format!("/crew/leave/{}", mission_id),
+            Some(&brawler.access_token),
+            None,
+        )
+        .await
+    }
+
+    pub async fn mission(&self, mission_id: i32) -> Value {
+        let response = self
+            .request(Method::GET, &format!("/view/{}", mission_id), None, None)
+            .await;
+        assert_eq!(response.status, StatusCode::OK, "{}", response.body);
+
+        response.body
+    }
+
+    async fn brawler_from_passport(&self, passport: Value) -> TestBrawler {
+        let access_token = passport["access_token"].as_str().unwrap().to_string();
+        let refresh_token = passport["refresh_token"].as_str().unwrap().to_string();
+
+        let me = self
+            .request(Method::GET, "/brawler/me", Some(&access_token), None)
+            .await;
+        assert_eq!(me.status, StatusCode::OK, "{}", me.body);
+
+        TestBrawler {
+            id: me.body["id"].as_i64().unwrap() as i32,
+            access_token,
+            refresh_token,
+        }
+    }
+}
+
+fn config() -> DotEnvyConfig {
+    DotEnvyConfig {
+        server: Server {
+            port: 0,
+            body_limit: 10,
+            timeout: 30,
+        },
+        database: Database {
+            url: String::new(),
+            max_connections: 1,
+            min_idle: None,
+            connection_timeout: 1,
+            idle_timeout: None,
+        },
+        jwt: JwtEnv {
+            signing_key: JwtKeyEnv {
+                key_id: "test".to_string(),
+                algorithm: "HS256".to_string(),
+                secret: Some("integration-test-secret".to_string()),
+                private_key_path: None,
+                public_key_path: None,
+            },
+            verification_keys: Vec::new(),
+            access_token_lifetime_minutes: 15,
+            refresh_token_lifetime_days: 7,
+        },
+        max_crew_per_mission: MAX_CREW_PER_MISSION,
+        max_avatar_size_kb: MAX_AVATAR_SIZE_KB,
+        image_storage: ImageStorageEnv::Local(LocalImageStorageEnv {
+            dir: "statics/uploads".to_string(),
+            base_url: "/uploads".to_string(),
+        }),
+    }
+}
@@ -0,0 +1,128 @@
+mod common;
+
+use axum::http::{Method, StatusCode};
+use serde_json::json;
+
+use common::{MAX_CREW_PER_MISSION, TestApp};
+
+#[tokio::test]
+async fn missions_stop_taking_crew_when_full() {
+    let app = TestApp::new().await;
+    let chief = app.register("chief").await;
+    let mission_id = app
+        .add_mission(&chief, json!({ "name": "Heist", "max_crew": 2 }))
+        .await;
+
+    for username in ["first", "second"] {
+        let brawler = app.register(username).await;
+        assert_eq!(app.join(&brawler, mission_id).await.status, StatusCode::OK);
+    }
+
+    let late = app.register("late").await;
+    let response = app.join(&late, mission_id).await;
+    assert_eq!(response.status, StatusCode::CONFLICT);
+    assert_eq!(response.body["message"], "Mission is full");
+    assert_eq!(app.mission(mission_id).await["crew_count"], 2);
+}
+
+#[tokio::test]
+async fn crew_bounds_cannot_exceed_the_server_limit() {
+    let app = TestApp::new().await;
+    let chief = app.register("chief").await;
+
+    for mission in [
+        json!({ "name": "Heist", "max_crew": MAX_CREW_PER_MISSION + 1 }),
+        json!({ "name": "Heist", "min_crew": 0 }),
+        json!({ "name": "Heist", "min_crew": 3, "max_crew": 2 }),
+    ] {
+        let response = app
+            .request(
+                Method::POST,
+                "/mission-management",
+                Some(&chief.access_token),
+                Some(mission),
+            )
+            .await;
+
+        assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(response.code(), "VALIDATION_FAILED");
+    }
+}
+
+#[tokio::test]
+async fn brawlers_join_a_mission_once_and_chiefs_never() {
+    let app = TestApp::new().await;
+    let chief = app.register("chief").await;
+    let crew = app.register("crew").await;
+    let mission_id = app.add_mission(&chief, json!({ "name": "Heist" })).await;
+
+    let chief_join = app.join(&chief, mission_id).await;
+    assert_eq!(chief_join.status, StatusCode::CONFLICT);
+
+    assert_eq!(app.join(&crew, mission_id).await.status, StatusCode::OK);
+    let again = app.join(&crew, mission_id).await;
+    assert_eq!(again.status, StatusCode::CONFLICT);
+    assert_eq!(again.body["message"], "Brawler has already joined this mission");
+}
+
+#[tokio::test]
+async fn missions_only_start_with_enough_crew() {
+    let app = TestApp::new().await;
+    let chief = app.register("chief").await;
+    let crew = app.register("crew").await;
+    let mission_id = app
+        .add_mission(&chief, json!({ "name": "Heist", "min_crew": 2 }))
+        .await;
+    assert_eq!(app.join(&crew, mission_id).await.status, StatusCode::OK);
+
+    let start = app
+        .request(
+            Method::PATCH,
+            &format!("/mission/in-progress/{}", mission_id),
+            Some(&chief.access_token),
+            None,
+        )
+        .await;
+    assert_eq!(start.status, StatusCode::CONFLICT);
+    assert_eq!(start.body["message"], "Invalid crew size to start the mission!");
+    assert_eq!(app.mission(mission_id).await["status"], "Open");
+}
+
+#[tokio::test]
+async fn crew_can_leave_only_open_missions_they_joined() {
+    let app = TestApp::new().await;
+    let chief = app.register("chief").await;
+    let crew = app.register("crew").await;
+    let mission_id = app.add_mission(&chief, json!({ "name": "Heist" })).await;
+    assert_eq!(app.leave(&crew, mission_id).await.status, StatusCode::NOT_FOUND);
+
+    assert_eq!(app.join(&crew, mission_id).await.status, StatusCode::OK);
+    let start = app
+        .request(
+            Method::PATCH,
+            &format!("/mission/in-progress/{}", mission_id),
+            Some(&chief.access_token),
+            None,
+        )
+        .await;
+    assert_eq!(start.status, StatusCode::OK);
+
+    let response = app.leave(&crew, mission_id).await;
+    assert_eq!(response.status, StatusCode::CONFLICT);
+    assert_eq!(response.body["message"], "Mission is not leavable");
+}
+
+#[tokio::test]
+async fn joining_needs_a_bearer_token() {
+    let app = TestApp::new().await;
+    let chief = app.register("chief").await;
+    let mission_id = app.add_mission(&chief, json!({ "name": "Heist" })).await;
+
+    let response = app
+        .request(Method::POST, &format!("/crew/join/{}", mission_id), None, None)
+        .await;
+    assert_eq!(response.status, StatusCode::UNAUTHORIZED);
+
+    let unknown_mission = app.join(&chief, mission_id + 1).await;
+    assert_eq!(unknown_mission.status, StatusCode::NOT_FOUND);
+}
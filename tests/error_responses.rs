@@ -0,0 +1,92 @@
+mod common;
+
+use axum::{
+    body::{Body, to_bytes},
+    http::{Method, Request, StatusCode, header},
+    response::IntoResponse,
+};
+use serde_json::{Value, json};
+use server::domain::errors::DomainError;
+
+use common::TestApp;
+
+#[tokio::test]
+async fn malformed_json_bodies_get_the_typed_error_body() {
+    let app = TestApp::new().await;
+    let login = |content_type: Option<&str>, body: &str| {
+        let mut request = Request::builder().method(Method::POST).uri("/auth/login");
+        if let Some(content_type) = content_type {
+            request = request.header(header::CONTENT_TYPE, content_type);
+        }
+        request.body(Body::from(body.to_string())).unwrap()
+    };
+
+    for request in [
+        login(Some("application/json"), "{"),
+        login(Some("application/json"), r#"{"username": 7}"#),
+        login(Some("text/plain"), r#"{"username": "alice", "password": "x"}"#),
+        login(None, r#"{"username": "alice", "password": "x"}"#),
+    ] {
+        let response = app.send(request).await;
+
+        assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY, "{}", response.body);
+        assert_eq!(response.code(), "VALIDATION_FAILED");
+        assert!(response.body["message"].is_string());
+    }
+}
+
+#[tokio::test]
+async fn malformed_path_and_query_parameters_get_the_typed_error_body() {
+    let app = TestApp::new().await;
+
+    for uri in ["/view/not-a-number", "/brawler/leaderboard?page=first"] {
+        let response = app.request(Method::GET, uri, None, None).await;
+
+        assert_eq!(response.status, StatusCode::UNPROCESSABLE_ENTITY, "{}: {}", uri, response.body);
+        assert_eq!(response.code(), "VALIDATION_FAILED");
+    }
+}
+
+#[tokio::test]
+async fn optional_bodies_may_be_left_out_but_not_malformed() {
+    let app = TestApp::new().await;
+    let chief = app.register("chief").await;
+    let mission_id = app.add_mission(&chief, json!({ "name": "Heist" })).await;
+
+    let malformed = app
+        .send(
+            Request::builder()
+                .method(Method::PATCH)
+                .uri(format!("/mission/to-cancelled/{}", mission_id))
+                .header(header::AUTHORIZATION, format!("Bearer {}", chief.access_token))
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Body::from("{"))
+                .unwrap(),
+        )
+        .await;
+    assert_eq!(malformed.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(malformed.code(), "VALIDATION_FAILED");
+
+    let left_out = app
+        .request(
+            Method::PATCH,
+            &format!("/mission/to-cancelled/{}", mission_id),
+            Some(&chief.access_token),
+            None,
+        )
+        .await;
+    assert_eq!(left_out.status, StatusCode::OK, "{}", left_out.body);
+}
+
+#[tokio::test]
+async fn upstream_details_stay_out_of_the_response() {
+    let response = DomainError::upstream("Cloudinary answered 401: Invalid api_key 1234")
+        .into_response();
+
+    assert_eq!(response.status(), StatusCode::BAD_GATEWAY);
+    let body: Value =
+        serde_json::from_slice(&to_bytes(response.into_body(), usize::MAX).await.unwrap())
+            .unwrap();
+    assert_eq!(body["code"], "UPSTREAM_FAILURE");
+    assert_eq!(body["message"], "An upstream service failed");
+}
@@ -0,0 +1,113 @@
+use chrono::{Duration, Utc};
+use jsonwebtoken::jwk::AlgorithmParameters;
+use server::{
+    config::config_model::{JwtEnv, JwtKeyEnv},
+    domain::value_objects::roles::Role,
+    infrastructure::jwt::{jwt_authority::JwtAuthority, jwt_model::Claims},
+};
+
+/// Test-only keys, see `tests/fixtures/jwt`.
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/jwt");
+
+/// `openssl rsa -pubin -in rsa.pub.pem -noout -modulus`, base64url encoded.
+const RSA_N: &str = "pY9uIaH05dAwa3QqW7-TnK6rNkEwaUJpKcuN1EylEGPmIZoSZ6VKbLcu8CsCmgYqe5mrwL5EAvr0eko1_L2WsCi_NEREqN7GLqMOcnM2gyROunKhamTOy5Q9vB6vj18K7Y3EFpDgCBi4_ixa9CgLxx7uzFFZC3FHm3Lj84owODluwCutgsR9Q3hh3-82O1BG-3xou6VEbIUaDlEnCQZiXFCZ45PMBIyOv9VQzhNgUP1sizw0cttfRSjRkEjHxDyqwo4gtybUBqGo_PSnOrNI4ku-UTY4GgApYduQQcWqeIPWHCc4eaC_jNBolt5dRRPlfqmuYymd97WRc4T0FjDcnQ";
+/// 65537.
+const RSA_E: &str = "AQAB";
+/// The last 32 bytes of `openssl pkey -pubin -in ed25519.pub.pem -outform DER`.
+const ED25519_X: &str = "vQ8KuRJwVIzWby3QARf6_lICvvqbdStslhi03qCdYp0";
+
+fn key(key_id: &str, algorithm: &str, private_key: &str, public_key: &str) -> JwtKeyEnv {
+    JwtKeyEnv {
+        key_id: key_id.to_string(),
+        algorithm: algorithm.to_string(),
+        secret: None,
+        private_key_path: Some(format!("{}/{}", FIXTURES, private_key)),
+        public_key_path: Some(format!("{}/{}", FIXTURES, public_key)),
+    }
+}
+
+fn jwt_env(signing_key: JwtKeyEnv, verification_keys: Vec<JwtKeyEnv>) -> JwtEnv {
+    JwtEnv {
+        signing_key,
+        verification_keys,
+        access_token_lifetime_minutes: 15,
+        refresh_token_lifetime_days: 7,
+    }
+}
+
+fn claims() -> Claims {
+    Claims {
+        sub: "7".to_string(),
+        exp: (Utc::now() + Duration::minutes(15)).timestamp() as usize,
+        iat: Utc::now().timestamp() as f64,
+        jti: "jti".to_string(),
+        role: Role::Brawler,
+    }
+}
+
+fn rsa_key() -> JwtKeyEnv {
+    key("rsa", "RS256", "rsa.pem", "rsa.pub.pem")
+}
+
+fn ed25519_key() -> JwtKeyEnv {
+    key("ed", "EdDSA", "ed25519.pem", "ed25519.pub.pem")
+}
+
+#[test]
+fn jwks_publish_the_configured_public_keys() {
+    let jwt_authority = JwtAuthority::from_env(&jwt_env(rsa_key(), vec![ed25519_key()])).unwrap();
+
+    let keys = &jwt_authority.jwks().keys;
+    assert_eq!(keys.len(), 2);
+    assert_eq!(keys[0].common.key_id.as_deref(), Some("rsa"));
+    match &keys[0].algorithm {
+        AlgorithmParameters::RSA(rsa) => {
+            assert_eq!(rsa.n, RSA_N);
+            assert_eq!(rsa.e, RSA_E);
+        }
+        other => panic!("expected an RSA key, got {:?}", other),
+    }
+    assert_eq!(keys[1].common.key_id.as_deref(), Some("ed"));
+    match &keys[1].algorithm {
+        AlgorithmParameters::OctetKeyPair(ed25519) => assert_eq!(ed25519.x, ED25519_X),
+        other => panic!("expected an Ed25519 key, got {:?}", other),
+    }
+}
+
+#[test]
+fn tokens_round_trip_with_either_algorithm() {
+    for signing_key in [rsa_key(), ed25519_key()] {
+        let jwt_authority = JwtAuthority::from_env(&jwt_env(signing_key, Vec::new())).unwrap();
+        let token = jwt_authority.sign(&claims()).unwrap();
+
+        assert_eq!(jwt_authority.verify(&token).unwrap().sub, "7");
+    }
+}
+
+#[test]
+fn keys_for_the_other_algorithm_are_rejected() {
+    let rsa_as_eddsa = key("ed", "EdDSA", "ed25519.pem", "rsa.pub.pem");
+    let ed25519_as_rsa = key("rsa", "RS256", "rsa.pem", "ed25519.pub.pem");
+
+    for signing_key in [rsa_as_eddsa, ed25519_as_rsa] {
+        let error = JwtAuthority::from_env(&jwt_env(signing_key, Vec::new()))
+            .err()
+            .unwrap();
+
+        assert!(format!("{:#}", error).contains("Expected an"), "{:#}", error);
+    }
+}
+
+#[test]
+fn tokens_do_not_verify_against_a_tampered_key() {
+    let jwt_authority = JwtAuthority::from_env(&jwt_env(rsa_key(), Vec::new())).unwrap();
+    let token = jwt_authority.sign(&claims()).unwrap();
+    let tampered = key("rsa", "RS256", "rsa.pem", "rsa_tampered.pub.pem");
+    let tampered_authority = JwtAuthority::from_env(&jwt_env(tampered, Vec::new())).unwrap();
+
+    assert!(tampered_authority.verify(&token).is_err());
+    match &tampered_authority.jwks().keys[0].algorithm {
+        AlgorithmParameters::RSA(rsa) => assert_ne!(rsa.n, RSA_N),
+        other => panic!("expected an RSA key, got {:?}", other),
+    }
+}
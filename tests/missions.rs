@@ -0,0 +1,269 @@
+mod common;
+
+use axum::http::{Method, StatusCode};
+use serde_json::json;
+
+use common::TestApp;
+
+#[tokio::test]
+async fn missions_go_from_open_to_completed() {
+    let app = TestApp::new().await;
+    let chief = app.register("chief").await;
+    let crew = app.register("crew").await;
+    let mission_id = app
+        .add_mission(&chief, json!({ "name": "Heist", "description": "Vault 13" }))
+        .await;
+
+    let mission = app.mission(mission_id).await;
+    assert_eq!(mission["status"], "Open");
+    assert_eq!(mission["chief_id"], chief.id);
+    assert_eq!(mission["min_crew"], 1);
+    assert_eq!(mission["max_crew"], common::MAX_CREW_PER_MISSION);
+
+    assert_eq!(app.join(&crew, mission_id).await.status, StatusCode::OK);
+
+    let in_progress = app
+        .request(
+            Method::PATCH,
+            &format!("/mission/in-progress/{}", mission_id),
+            Some(&chief.access_token),
+            None,
+        )
+        .await;
+    assert_eq!(in_progress.status, StatusCode::OK);
+
+    let completed = app
+        .request(
+            Method::PATCH,
+            &format!("/mission/to-completed/{}", mission_id),
+            Some(&chief.access_token),
+            Some(json!({ "reason": "Vault emptied" })),
+        )
+        .await;
+    assert_eq!(completed.status, StatusCode::OK);
+
+    let mission = app.mission(mission_id).await;
+    assert_eq!(mission["status"], "Completed");
+    assert_eq!(mission["crew_count"], 1);
+
+    let history = app
+        .request(Method::GET, &format!("/view/{}/history", mission_id), None, None)
+        .await;
+    assert_eq!(history.status, StatusCode::OK);
+    let transitions = history
+        .body
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| (entry["from_status"].clone(), entry["to_status"].clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        transitions,
+        vec![
+            (json!("Open"), json!("InProgress")),
+            (json!("InProgress"), json!("Completed")),
+        ]
+    );
+    assert_eq!(history.body[1]["reason"], "Vault emptied");
+    assert_eq!(history.body[1]["actor_id"], chief.id);
+}
+
+#[tokio::test]
+async fn finished_missions_cannot_be_reopened() {
+    let app = TestApp::new().await;
+    let chief = app.register("chief").await;
+    let mission_id = app.add_mission(&chief, json!({ "name": "Heist" })).await;
+
+    let cancelled = app
+        .request(
+            Method::PATCH,
+            &format!("/mission/to-cancelled/{}", mission_id),
+            Some(&chief.access_token),
+            None,
+        )
+        .await;
+    assert_eq!(cancelled.status, StatusCode::OK);
+
+    let re_open = app
+        .request(
+            Method::PATCH,
+            &format!("/mission/re-open/{}", mission_id),
+            Some(&chief.access_token),
+            None,
+        )
+        .await;
+    assert_eq!(re_open.status, StatusCode::CONFLICT);
+    assert_eq!(re_open.code(), "INVALID_TRANSITION");
+}
+
+#[tokio::test]
+async fn missions_that_never_started_cannot_fail() {
+    let app = TestApp::new().await;
+    let chief = app.register("chief").await;
+    let mission_id = app.add_mission(&chief, json!({ "name": "Heist" })).await;
+
+    let to_failed = app
+        .request(
+            Method::PATCH,
+            &format!("/mission/to-failed/{}", mission_id),
+            Some(&chief.access_token),
+            None,
+        )
+        .await;
+    assert_eq!(to_failed.status, StatusCode::CONFLICT);
+    assert_eq!(to_failed.code(), "INVALID_TRANSITION");
+
+    assert_eq!(app.mission(mission_id).await["status"], "Open");
+}
+
+#[tokio::test]
+async fn only_the_chief_changes_the_mission() {
+    let app = TestApp::new().await;
+    let chief = app.register("chief").await;
+    let other = app.register("other").await;
+    let mission_id = app.add_mission(&chief, json!({ "name": "Heist" })).await;
+
+    let to_failed = app
+        .request(
+            Method::PATCH,
+            &format!("/mission/to-failed/{}", mission_id),
+            Some(&other.access_token),
+            None,
+        )
+        .await;
+    assert_eq!(to_failed.status, StatusCode::FORBIDDEN);
+
+    let edit = app
+        .request(
+            Method::PATCH,
+            &format!("/mission-management/{}", mission_id),
+            Some(&other.access_token),
+            Some(json!({ "name": "Stolen heist" })),
+        )
+        .await;
+    assert_eq!(edit.status, StatusCode::FORBIDDEN);
+
+    let remove = app
+        .request(
+            Method::DELETE,
+            &format!("/mission-management/{}", mission_id),
+            Some(&other.access_token),
+            None,
+        )
+        .await;
+    assert_eq!(remove.status, StatusCode::FORBIDDEN);
+
+    assert_eq!(app.mission(mission_id).await["name"], "Heist");
+}
+
+#[tokio::test]
+async fn removed_missions_are_hidden_until_an_admin_restores_them() {
+    let app = TestApp::new().await;
+    let chief = app.register("chief").await;
+    let admin = app.register_admin("admin").await;
+    let mission_id = app.add_mission(&chief, json!({ "name": "Heist" })).await;
+
+    let remove = app
+        .request(
+            Method::DELETE,
+            &format!("/mission-management/{}", mission_id),
+            Some(&chief.access_token),
+            None,
+        )
+        .await;
+    assert_eq!(remove.status, StatusCode::OK);
+
+    let hidden = app
+        .request(Method::GET, &format!("/view/{}", mission_id), None, None)
+        .await;
+    assert_eq!(hidden.status, StatusCode::NOT_FOUND);
+
+    let listed = app.request(Method::GET, "/view/gets", None, None).await;
+    assert_eq!(listed.body["missions"], json!([]));
+
+    let restore = app
+        .request(
+            Method::PATCH,
+            &format!("/admin/missions/{}/restore", mission_id),
+            Some(&admin.access_token),
+            None,
+        )
+        .await;
+    assert_eq!(restore.status, StatusCode::OK);
+
+    assert_eq!(app.mission(mission_id).await["status"], "Open");
+}
+
+#[tokio::test]
+async fn admins_can_force_close_any_mission() {
+    let app = TestApp::new().await;
+    let chief = app.register("chief").await;
+    let admin = app.register_admin("admin").await;
+    let mission_id = app.add_mission(&chief, json!({ "name": "Heist" })).await;
+
+    let close = app
+        .request(
+            Method::PATCH,
+            &format!("/admin/missions/{}/close", mission_id),
+            Some(&admin.access_token),
+            Some(json!({ "reason": "Reported" })),
+        )
+        .await;
+    assert_eq!(close.status, StatusCode::OK);
+
+    assert_eq!(app.mission(mission_id).await["status"], "Failed");
+}
+
+#[tokio::test]
+async fn missions_are_listed_with_filters() {
+    let app = TestApp::new().await;
+    let chief = app.register("chief").await;
+    let crew = app.register("crew").await;
+    app.add_mission(&chief, json!({ "name": "Bank Heist" })).await;
+    let rescue = app
+        .add_mission(&chief, json!({ "name": "Rescue", "max_crew": 1 }))
+        .await;
+    app.add_mission(&chief, json!({ "name": "Train heist" })).await;
+    assert_eq!(app.join(&crew, rescue).await.status, StatusCode::OK);
+
+    let names = |response: common::Response| {
+        response.body["missions"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|mission| mission["name"].as_str().unwrap().to_string())
+            .collect::<Vec<_>>()
+    };
+
+    let by_name = app
+        .request(Method::GET, "/view/gets?name=HEIST&sort_by=name&order=asc", None, None)
+        .await;
+    assert_eq!(names(by_name), vec!["Bank Heist", "Train heist"]);
+
+    let full = app
+        .request(Method::GET, "/view/gets?has_free_slots=false", None, None)
+        .await;
+    assert_eq!(names(full), vec!["Rescue"]);
+
+    let first_page = app
+        .request(Method::GET, "/view/gets?limit=2&sort_by=name&order=asc", None, None)
+        .await;
+    let cursor = first_page.body["next_cursor"].as_str().unwrap().to_string();
+    assert_eq!(names(first_page), vec!["Bank Heist", "Rescue"]);
+
+    let second_page = app
+        .request(
+            Method::GET,
+            &format!("/view/gets?limit=2&sort_by=name&order=asc&cursor={}", cursor),
+            None,
+            None,
+        )
+        .await;
+    assert_eq!(second_page.body["next_cursor"], json!(null));
+    assert_eq!(names(second_page), vec!["Train heist"]);
+
+    let joined = app
+        .request(Method::GET, "/view/gets?joined=true", Some(&crew.access_token), None)
+        .await;
+    assert_eq!(names(joined), vec!["Rescue"]);
+}
@@ -0,0 +1,848 @@
+//! Runs the Postgres repositories against a throwaway database per test, see
+//! [`common::database::TestDatabase`]. Ignored by default, run them with
+//! `TEST_DATABASE_URL` set and `cargo test --test postgres_repositories -- --ignored`.
+
+mod common;
+
+use std::sync::Arc;
+
+use chrono::{Duration, NaiveDateTime, SubsecRound, Utc};
+use diesel::{
+    ExpressionMethods, QueryDsl, QueryableByName, RunQueryDsl, sql_query, sql_types::Integer,
+};
+use diesel_migrations::MigrationHarness;
+use server::{
+    application::use_cases::mission_management::MissionManagementUseCase,
+    domain::{
+        entities::{
+            brawlers::RegisterBrawlerEntity, crew_memberships::CrewMembershipEntity,
+            mission_status_history::AddMissionStatusHistoryEntity,
+            missions::{AddMissionEntity, MissionEntity},
+            refresh_tokens::AddRefreshTokenEntity, revoked_tokens::AddRevokedTokenEntity,
+        },
+        errors::DomainError,
+        repositories::{
+            brawlers::BrawlerRepository,
+            mission_management::MissionManagementRepository,
+            mission_viewing::MissionViewingRepository,
+            pending_image_deletions::PendingImageDeletionRepository,
+            refresh_tokens::RefreshTokenRepository,
+            token_revocation::TokenRevocationRepository,
+            transaction_provider::{MissionTransaction, TransactionProvider},
+        },
+        value_objects::{
+            brawler_model::BrawlerModel,
+            leaderboard_filter::LeaderboardSort,
+            mission_filter::{MissionKeyset, MissionQuery, MissionSort, SortOrder},
+            mission_statuses::MissionStatuses,
+            uploaded_image::{AvatarSizes, AvatarUrl, UploadedImage},
+        },
+    },
+    infrastructure::database::{
+        postgresql_connection::{MIGRATIONS, PgPoolSquad, run_migrations},
+        repositories::{
+            brawlers::BrawlerPostgres, mission_management::MissionManagementPostgres,
+            mission_viewing::MissionViewingPostgres,
+            pending_image_deletions::PendingImageDeletionPostgres,
+            refresh_tokens::RefreshTokenPostgres,
+            token_revocation::TokenRevocationPostgres,
+            transaction_provider::PostgresTransactionProvider,
+        },
+        schema::missions,
+    },
+};
+
+use common::database::TestDatabase;
+use uuid::Uuid;
+
+async fn register(db_pool: &Arc<PgPoolSquad>, username: &str) -> i32 {
+    BrawlerPostgres::new(Arc::clone(db_pool))
+        .register(RegisterBrawlerEntity {
+            username: username.to_string(),
+            password: "hashed".to_string(),
+            display_name: username.to_string(),
+        })
+        .await
+        .unwrap()
+}
+
+async fn add_mission(db_pool: &Arc<PgPoolSquad>, chief_id: i32, name: &str) -> i32 {
+    MissionManagementPostgres::new(Arc::clone(db_pool))
+        .add(AddMissionEntity {
+            chief_id,
+            name: name.to_string(),
+            status: MissionStatuses::Open,
+            description: None,
+            min_crew: 1,
+            max_crew: 3,
+        })
+        .await
+        .unwrap()
+}
+
+/// Sets the session time zone of every pooled connection, far enough from UTC
+/// that timestamps taken with SQL `now()` would stand out.
+fn set_time_zone(db_pool: &Arc<PgPoolSquad>, time_zone: &str) {
+    // Holds every pooled connection at once so each of them gets the setting.
+    let connections = (0..db_pool.max_size())
+        .map(|_| db_pool.get().unwrap())
+        .collect::<Vec<_>>();
+    for mut conn in connections {
+        sql_query(format!("SET TIME ZONE '{}'", time_zone))
+            .execute(&mut conn)
+            .unwrap();
+    }
+}
+
+/// Reverts every migration from `version` on, to seed data the way it was
+/// before them.
+fn revert_migrations_from(db_pool: &Arc<PgPoolSquad>, version: &str) {
+    let mut conn = db_pool.get().unwrap();
+    while conn
+        .applied_migrations()
+        .unwrap()
+        .iter()
+        .any(|applied| applied.to_string().as_str() >= version)
+    {
+        conn.revert_last_migration(MIGRATIONS).unwrap();
+    }
+}
+
+#[derive(QueryableByName)]
+struct MissionId {
+    #[diesel(sql_type = Integer)]
+    id: i32,
+}
+
+/// Runs `query` as is, to set up rows the repositories have no way to write.
+fn execute(db_pool: &Arc<PgPoolSquad>, query: &str) {
+    sql_query(query)
+        .execute(&mut db_pool.get().unwrap())
+        .unwrap();
+}
+
+/// Every mission id the listing returns, fetched two at a time with keyset
+/// pagination.
+async fn page_through(
+    mission_viewing: &MissionViewingPostgres,
+    sort_by: MissionSort,
+    order: SortOrder,
+) -> Vec<i32> {
+    let mut ids = Vec::new();
+    let mut after = None;
+    loop {
+        let page = mission_viewing
+            .get_with_crew_counts(&MissionQuery {
+                sort_by,
+                order,
+                after,
+                limit: 2,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let Some((last, _)) = page.last() else {
+            return ids;
+        };
+        after = Some(match sort_by {
+            MissionSort::CreatedAt => MissionKeyset::Timestamp(last.created_at, last.id),
+            MissionSort::UpdatedAt => MissionKeyset::Timestamp(last.updated_at, last.id),
+            MissionSort::Name => MissionKeyset::Name(last.name.clone(), last.id),
+        });
+        ids.extend(page.iter().map(|(mission, _)| mission.id));
+    }
+}
+
+fn query_by_name() -> MissionQuery {
+    MissionQuery {
+        sort_by: MissionSort::Name,
+        order: SortOrder::Asc,
+        limit: 20,
+        ..Default::default()
+    }
+}
+
+fn names(missions: Vec<(MissionEntity, i64)>) -> Vec<String> {
+    missions.into_iter().map(|(mission, _)| mission.name).collect()
+}
+
+#[tokio::test]
+#[ignore = "needs Postgres, see TEST_DATABASE_URL"]
+async fn register_rejects_taken_usernames() {
+    let database = TestDatabase::create();
+    let brawler_repository = BrawlerPostgres::new(Arc::clone(&database.db_pool));
+    register(&database.db_pool, "alice").await;
+
+    let error = brawler_repository
+        .register(RegisterBrawlerEntity {
+            username: "alice".to_string(),
+            password: "other".to_string(),
+            display_name: "Other Alice".to_string(),
+        })
+        .await
+        .unwrap_err();
+
+    assert!(matches!(DomainError::from(error), DomainError::Conflict(_)));
+    assert_eq!(
+        brawler_repository.find_by_username("alice").await.unwrap().display_name,
+        "alice"
+    );
+}
+
+#[tokio::test]
+#[ignore = "needs Postgres, see TEST_DATABASE_URL"]
+async fn avatar_sizes_are_stored_with_the_avatar() {
+    let database = TestDatabase::create();
+    let brawlers = BrawlerPostgres::new(Arc::clone(&database.db_pool));
+    let mission_viewing = MissionViewingPostgres::new(Arc::clone(&database.db_pool));
+    let chief_id = register(&database.db_pool, "chief").await;
+    let mission_id = add_mission(&database.db_pool, chief_id, "Heist").await;
+    let avatar_sizes = AvatarSizes(
+        [64, 128, 256]
+            .into_iter()
+            .map(|size| AvatarUrl {
+                size,
+                url: format!("https://images.example/avatar_{}.png", size),
+            })
+            .collect(),
+    );
+
+    assert_eq!(brawlers.find_by_id(chief_id).await.unwrap().avatar_sizes, AvatarSizes::default());
+    brawlers
+        .update_avatar(
+            chief_id,
+            UploadedImage::new(
+                "https://images.example/avatar_256.png".to_string(),
+                "avatar_256".to_string(),
+            ),
+            avatar_sizes.clone(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(brawlers.find_by_id(chief_id).await.unwrap().avatar_sizes, avatar_sizes);
+    assert_eq!(brawlers.find_profile(chief_id).await.unwrap().avatar_sizes, avatar_sizes);
+    let mission_brawlers = mission_viewing.get_mission_brawlers(mission_id).await.unwrap();
+    assert_eq!(mission_brawlers[0].avatar_sizes, avatar_sizes);
+
+    brawlers.remove_avatar(chief_id).await.unwrap();
+    assert_eq!(brawlers.find_by_id(chief_id).await.unwrap().avatar_sizes, AvatarSizes::default());
+}
+
+#[tokio::test]
+#[ignore = "needs Postgres, see TEST_DATABASE_URL"]
+async fn pending_image_deletions_keep_their_attempts_when_queued_again() {
+    let database = TestDatabase::create();
+    let pending_image_deletions = PendingImageDeletionPostgres::new(Arc::clone(&database.db_pool));
+    let attempts = || async {
+        pending_image_deletions
+            .pending()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|pending| (pending.public_id, pending.attempts))
+            .collect::<Vec<_>>()
+    };
+
+    pending_image_deletions
+        .add(vec!["a".to_string(), "b".to_string()])
+        .await
+        .unwrap();
+    assert_eq!(pending_image_deletions.record_failure("a").await.unwrap(), 1);
+    assert_eq!(pending_image_deletions.record_failure("a").await.unwrap(), 2);
+    pending_image_deletions
+        .add(vec!["a".to_string(), "c".to_string()])
+        .await
+        .unwrap();
+    assert_eq!(
+        attempts().await,
+        vec![("a".to_string(), 2), ("b".to_string(), 0), ("c".to_string(), 0)]
+    );
+
+    pending_image_deletions.remove("a").await.unwrap();
+    pending_image_deletions.remove("a").await.unwrap();
+    assert_eq!(attempts().await, vec![("b".to_string(), 0), ("c".to_string(), 0)]);
+    assert!(pending_image_deletions.record_failure("a").await.is_err());
+}
+
+#[tokio::test]
+#[ignore = "needs Postgres, see TEST_DATABASE_URL"]
+async fn removed_missions_are_filtered_out() {
+    let database = TestDatabase::create();
+    let mission_management = MissionManagementPostgres::new(Arc::clone(&database.db_pool));
+    let mission_viewing = MissionViewingPostgres::new(Arc::clone(&database.db_pool));
+    let transaction_provider = PostgresTransactionProvider::new(Arc::clone(&database.db_pool));
+    let chief_id = register(&database.db_pool, "chief").await;
+    let kept = add_mission(&database.db_pool, chief_id, "Kept").await;
+    let removed = add_mission(&database.db_pool, chief_id, "Removed").await;
+
+    transaction_provider
+        .transaction(move |transaction| Ok(transaction.remove_mission(removed, chief_id)?))
+        .await
+        .unwrap();
+
+    assert_eq!(mission_viewing.get_one(kept).await.unwrap().name, "Kept");
+    let error = mission_viewing.get_one(removed).await.unwrap_err();
+    assert!(matches!(DomainError::from(error), DomainError::NotFound(_)));
+
+    let listed = mission_viewing
+        .get_with_crew_counts(&query_by_name())
+        .await
+        .unwrap();
+    assert_eq!(names(listed), vec!["Kept"]);
+
+    let by_id = mission_viewing
+        .get_with_crew_counts(&MissionQuery {
+            mission_id: Some(removed),
+            ..query_by_name()
+        })
+        .await
+        .unwrap();
+    assert!(by_id.is_empty());
+
+    assert!(mission_viewing.get_mission_brawlers(removed).await.is_err());
+    assert!(mission_viewing.get_status_history(removed).await.is_err());
+
+    mission_management.restore(removed).await.unwrap();
+    assert_eq!(mission_viewing.get_one(removed).await.unwrap().name, "Removed");
+}
+
+#[tokio::test]
+#[ignore = "needs Postgres, see TEST_DATABASE_URL"]
+async fn name_filter_is_a_case_insensitive_substring_match() {
+    let database = TestDatabase::create();
+    let mission_viewing = MissionViewingPostgres::new(Arc::clone(&database.db_pool));
+    let chief_id = register(&database.db_pool, "chief").await;
+    for name in ["Bank Heist", "train HEIST", "Heistless", "Rescue"] {
+        add_mission(&database.db_pool, chief_id, name).await;
+    }
+
+    let heists = mission_viewing
+        .get_with_crew_counts(&MissionQuery {
+            name: Some("heist".to_string()),
+            ..query_by_name()
+        })
+        .await
+        .unwrap();
+    assert_eq!(names(heists), vec!["Bank Heist", "Heistless", "train HEIST"]);
+
+    let none = mission_viewing
+        .get_with_crew_counts(&MissionQuery {
+            name: Some("vault".to_string()),
+            ..query_by_name()
+        })
+        .await
+        .unwrap();
+    assert!(none.is_empty());
+}
+
+#[tokio::test]
+#[ignore = "needs Postgres, see TEST_DATABASE_URL"]
+async fn name_filter_takes_wildcards_literally() {
+    let database = TestDatabase::create();
+    let mission_viewing = MissionViewingPostgres::new(Arc::clone(&database.db_pool));
+    let chief_id = register(&database.db_pool, "chief").await;
+    for name in ["100% Heist", "Bank_Heist", "Back\\Door", "Bank Heist"] {
+        add_mission(&database.db_pool, chief_id, name).await;
+    }
+
+    for (name, expected) in [
+        ("%", vec!["100% Heist"]),
+        ("_", vec!["Bank_Heist"]),
+        ("\\", vec!["Back\\Door"]),
+        ("bank_", vec!["Bank_Heist"]),
+    ] {
+        let found = mission_viewing
+            .get_with_crew_counts(&MissionQuery {
+                name: Some(name.to_string()),
+                ..query_by_name()
+            })
+            .await
+            .unwrap();
+        assert_eq!(names(found), expected, "{}", name);
+    }
+}
+
+#[tokio::test]
+#[ignore = "needs Postgres, see TEST_DATABASE_URL"]
+async fn keyset_pages_cover_ties_on_the_sort_column_in_both_orders() {
+    let database = TestDatabase::create();
+    let mission_viewing = MissionViewingPostgres::new(Arc::clone(&database.db_pool));
+    let chief_id = register(&database.db_pool, "chief").await;
+    let mut missions = Vec::new();
+    for name in ["Bravo", "Alpha", "Bravo", "Charlie", "Bravo"] {
+        missions.push((name, add_mission(&database.db_pool, chief_id, name).await));
+    }
+    // The three Bravos share a creation time as well.
+    execute(
+        &database.db_pool,
+        "UPDATE missions SET created_at = '2026-01-01 00:00:00' WHERE name = 'Bravo'",
+    );
+    execute(
+        &database.db_pool,
+        "UPDATE missions SET created_at = '2025-01-01 00:00:00' WHERE name = 'Alpha'",
+    );
+    execute(
+        &database.db_pool,
+        "UPDATE missions SET created_at = '2027-01-01 00:00:00' WHERE name = 'Charlie'",
+    );
+
+    let mut by_name = missions.clone();
+    by_name.sort();
+    let by_name = by_name.into_iter().map(|(_, id)| id).collect::<Vec<_>>();
+    let mut by_name_desc = by_name.clone();
+    by_name_desc.reverse();
+
+    assert_eq!(
+        page_through(&mission_viewing, MissionSort::Name, SortOrder::Asc).await,
+        by_name
+    );
+    assert_eq!(
+        page_through(&mission_viewing, MissionSort::Name, SortOrder::Desc).await,
+        by_name_desc
+    );
+    // Alpha, Bravo and Charlie were created in name order.
+    assert_eq!(
+        page_through(&mission_viewing, MissionSort::CreatedAt, SortOrder::Asc).await,
+        by_name
+    );
+    assert_eq!(
+        page_through(&mission_viewing, MissionSort::CreatedAt, SortOrder::Desc).await,
+        by_name_desc
+    );
+}
+
+#[tokio::test]
+#[ignore = "needs Postgres, see TEST_DATABASE_URL"]
+async fn free_slots_are_counted_against_each_missions_own_crew_bound() {
+    let database = TestDatabase::create();
+    let mission_viewing = MissionViewingPostgres::new(Arc::clone(&database.db_pool));
+    let chief_id = register(&database.db_pool, "chief").await;
+    let full = add_mission(&database.db_pool, chief_id, "Full").await;
+    let roomy = add_mission(&database.db_pool, chief_id, "Roomy").await;
+    add_mission(&database.db_pool, chief_id, "Empty").await;
+    execute(
+        &database.db_pool,
+        &format!("UPDATE missions SET max_crew = 1 WHERE id = {}", full),
+    );
+    for (index, mission_id) in [full, roomy].into_iter().enumerate() {
+        let brawler_id = register(&database.db_pool, &format!("crew{}", index)).await;
+        execute(
+            &database.db_pool,
+            &format!(
+                "INSERT INTO crew_memberships (mission_id, brawler_id) VALUES ({}, {})",
+                mission_id, brawler_id
+            ),
+        );
+    }
+
+    let with_counts = |missions: Vec<(MissionEntity, i64)>| {
+        missions
+            .into_iter()
+            .map(|(mission, crew_count)| (mission.name, crew_count))
+            .collect::<Vec<_>>()
+    };
+    let free = mission_viewing
+        .get_with_crew_counts(&MissionQuery {
+            has_free_slots: Some(true),
+            ..query_by_name()
+        })
+        .await
+        .unwrap();
+    assert_eq!(
+        with_counts(free),
+        vec![("Empty".to_string(), 0), ("Roomy".to_string(), 1)]
+    );
+
+    let taken = mission_viewing
+        .get_with_crew_counts(&MissionQuery {
+            has_free_slots: Some(false),
+            ..query_by_name()
+        })
+        .await
+        .unwrap();
+    assert_eq!(with_counts(taken), vec![("Full".to_string(), 1)]);
+}
+
+#[tokio::test]
+#[ignore = "needs Postgres, see TEST_DATABASE_URL"]
+async fn mission_brawlers_list_the_chief_then_the_crew_by_joining_time() {
+    let database = TestDatabase::create();
+    let mission_viewing = MissionViewingPostgres::new(Arc::clone(&database.db_pool));
+    let chief_id = register(&database.db_pool, "chief").await;
+    let first = register(&database.db_pool, "first").await;
+    let second = register(&database.db_pool, "second").await;
+    let earliest = register(&database.db_pool, "earliest").await;
+    let mission_id = add_mission(&database.db_pool, chief_id, "Heist").await;
+    execute(
+        &database.db_pool,
+        &format!(
+            "INSERT INTO crew_memberships (mission_id, brawler_id, joined_at) VALUES \
+             ({mission_id}, {second}, '2026-01-02'), ({mission_id}, {first}, '2026-01-02'), \
+             ({mission_id}, {earliest}, '2026-01-01')"
+        ),
+    );
+
+    let brawlers = mission_viewing.get_mission_brawlers(mission_id).await.unwrap();
+
+    assert_eq!(
+        brawlers
+            .iter()
+            .map(|brawler| (brawler.id, brawler.is_chief))
+            .collect::<Vec<_>>(),
+        vec![
+            (chief_id, true),
+            (earliest, false),
+            (first, false),
+            (second, false)
+        ]
+    );
+}
+
+#[tokio::test]
+#[ignore = "needs Postgres, see TEST_DATABASE_URL"]
+async fn brawler_statistics_skip_removed_missions() {
+    let database = TestDatabase::create();
+    let brawlers = BrawlerPostgres::new(Arc::clone(&database.db_pool));
+    let chief_id = register(&database.db_pool, "chief").await;
+    let veteran = register(&database.db_pool, "veteran").await;
+    let rookie = register(&database.db_pool, "rookie").await;
+    let completed = add_mission(&database.db_pool, chief_id, "Completed").await;
+    let failed = add_mission(&database.db_pool, chief_id, "Failed").await;
+    let removed = add_mission(&database.db_pool, chief_id, "Removed").await;
+    for query in [
+        format!("UPDATE missions SET status = 'Completed' WHERE id IN ({completed}, {removed})"),
+        format!("UPDATE missions SET status = 'Failed' WHERE id = {failed}"),
+        format!("UPDATE missions SET deleted_at = '2026-01-01' WHERE id = {removed}"),
+        format!(
+            "INSERT INTO crew_memberships (mission_id, brawler_id) VALUES \
+             ({completed}, {veteran}), ({failed}, {veteran}), ({removed}, {veteran}), \
+             ({completed}, {rookie}), ({removed}, {rookie})"
+        ),
+    ] {
+        execute(&database.db_pool, &query);
+    }
+    let stats = |brawler: &BrawlerModel| {
+        (
+            brawler.id,
+            brawler.mission_success_count,
+            brawler.mission_joined_count,
+            brawler.mission_failed_count,
+            brawler.mission_led_count,
+        )
+    };
+
+    let profile = brawlers.find_profile(veteran).await.unwrap();
+    assert_eq!(stats(&profile), (veteran, 1, 2, 1, 0));
+
+    let by_success = brawlers
+        .leaderboard(LeaderboardSort::Success, 10, 0)
+        .await
+        .unwrap();
+    assert_eq!(
+        by_success.iter().map(stats).collect::<Vec<_>>(),
+        vec![
+            (veteran, 1, 2, 1, 0),
+            (rookie, 1, 1, 0, 0),
+            (chief_id, 0, 0, 0, 2)
+        ]
+    );
+
+    let led = brawlers.leaderboard(LeaderboardSort::Led, 1, 0).await.unwrap();
+    assert_eq!(led.iter().map(|brawler| brawler.id).collect::<Vec<_>>(), vec![chief_id]);
+    let second_by_joined = brawlers
+        .leaderboard(LeaderboardSort::Joined, 1, 1)
+        .await
+        .unwrap();
+    assert_eq!(
+        second_by_joined.iter().map(|brawler| brawler.id).collect::<Vec<_>>(),
+        vec![rookie]
+    );
+}
+
+#[tokio::test]
+#[ignore = "needs Postgres, see TEST_DATABASE_URL"]
+async fn crew_memberships_are_unique_per_mission() {
+    let database = TestDatabase::create();
+    let transaction_provider = PostgresTransactionProvider::new(Arc::clone(&database.db_pool));
+    let mission_viewing = MissionViewingPostgres::new(Arc::clone(&database.db_pool));
+    let chief_id = register(&database.db_pool, "chief").await;
+    let brawler_id = register(&database.db_pool, "crew").await;
+    let mission_id = add_mission(&database.db_pool, chief_id, "Heist").await;
+    let join = move |transaction: &mut _| {
+        MissionTransaction::join(
+            transaction,
+            CrewMembershipEntity {
+                brawler_id,
+                mission_id,
+            },
+        )
+        .map_err(DomainError::from)
+    };
+
+    transaction_provider.transaction(join).await.unwrap();
+    let error = transaction_provider.transaction(join).await.unwrap_err();
+
+    assert!(matches!(error, DomainError::Conflict(_)));
+    assert_eq!(mission_viewing.crew_counting(mission_id).await.unwrap(), 1);
+    assert_eq!(
+        mission_viewing.joined_mission_ids(brawler_id).await.unwrap(),
+        vec![mission_id]
+    );
+}
+
+#[tokio::test]
+#[ignore = "needs Postgres, see TEST_DATABASE_URL"]
+async fn crew_memberships_need_an_existing_mission() {
+    let database = TestDatabase::create();
+    let transaction_provider = PostgresTransactionProvider::new(Arc::clone(&database.db_pool));
+    let brawler_id = register(&database.db_pool, "crew").await;
+
+    let error = transaction_provider
+        .transaction(move |transaction| {
+            transaction
+                .join(CrewMembershipEntity {
+                    brawler_id,
+                    mission_id: 404,
+                })
+                .map_err(DomainError::from)
+        })
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, DomainError::Conflict(_)));
+}
+
+#[tokio::test]
+#[ignore = "needs Postgres, see TEST_DATABASE_URL"]
+async fn missions_cannot_be_removed_while_a_join_is_committing() {
+    let database = TestDatabase::create();
+    let transaction_provider = Arc::new(PostgresTransactionProvider::new(Arc::clone(
+        &database.db_pool,
+    )));
+    let mission_management = MissionManagementUseCase::new(
+        Arc::new(MissionManagementPostgres::new(Arc::clone(&database.db_pool))),
+        Arc::clone(&transaction_provider),
+        3,
+    );
+    let mission_viewing = MissionViewingPostgres::new(Arc::clone(&database.db_pool));
+    let chief_id = register(&database.db_pool, "chief").await;
+    let brawler_id = register(&database.db_pool, "crew").await;
+    let mission_id = add_mission(&database.db_pool, chief_id, "Heist").await;
+
+    // Joins and keeps the transaction open for a moment after the insert.
+    let (joined, wait_for_join) = tokio::sync::oneshot::channel();
+    let join = tokio::spawn({
+        let transaction_provider = Arc::clone(&transaction_provider);
+        async move {
+            transaction_provider
+                .transaction(move |transaction| {
+                    transaction.lock_mission(mission_id)?;
+                    transaction.join(CrewMembershipEntity {
+                        brawler_id,
+                        mission_id,
+                    })?;
+                    joined.send(()).unwrap();
+                    std::thread::sleep(std::time::Duration::from_millis(300));
+
+                    Ok(())
+                })
+                .await
+        }
+    });
+    wait_for_join.await.unwrap();
+
+    let error = mission_management
+        .remove(mission_id, chief_id)
+        .await
+        .unwrap_err();
+
+    join.await.unwrap().unwrap();
+    assert!(matches!(error, DomainError::Conflict(_)), "{:?}", error);
+    assert_eq!(mission_viewing.crew_counting(mission_id).await.unwrap(), 1);
+    assert!(mission_viewing.get_one(mission_id).await.is_ok());
+}
+
+#[tokio::test]
+#[ignore = "needs Postgres, see TEST_DATABASE_URL"]
+async fn revocation_times_are_utc_whatever_the_session_time_zone() {
+    let database = TestDatabase::create();
+    set_time_zone(&database.db_pool, "Pacific/Kiritimati");
+    let token_revocation = TokenRevocationPostgres::new(Arc::clone(&database.db_pool));
+    let brawler_id = register(&database.db_pool, "alice").await;
+
+    let before = Utc::now().naive_utc().trunc_subsecs(6);
+    let cutoff = token_revocation.revoke_all(brawler_id).await.unwrap();
+    let after = Utc::now().naive_utc();
+
+    assert!(before <= cutoff && cutoff <= after, "{} not in {}..{}", cutoff, before, after);
+    assert_eq!(
+        token_revocation.session_cutoffs().await.unwrap(),
+        vec![(brawler_id, cutoff)]
+    );
+
+    token_revocation
+        .revoke(AddRevokedTokenEntity {
+            jti: Uuid::new_v4(),
+            brawler_id,
+            expires_at: after + Duration::hours(1),
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(token_revocation.remove_expired().await.unwrap(), 0);
+    assert_eq!(token_revocation.active_revocations().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+#[ignore = "needs Postgres, see TEST_DATABASE_URL"]
+async fn refresh_token_times_are_utc_whatever_the_session_time_zone() {
+    let database = TestDatabase::create();
+    set_time_zone(&database.db_pool, "Pacific/Kiritimati");
+    let refresh_tokens = RefreshTokenPostgres::new(Arc::clone(&database.db_pool));
+    let brawler_id = register(&database.db_pool, "alice").await;
+    let family_id = Uuid::new_v4();
+    let token = |token_hash: &str| AddRefreshTokenEntity {
+        brawler_id,
+        family_id,
+        token_hash: token_hash.to_string(),
+        expires_at: Utc::now().naive_utc() + Duration::days(1),
+    };
+    refresh_tokens.add(token("first")).await.unwrap();
+    let first = refresh_tokens.find_by_hash("first").await.unwrap();
+
+    let before = Utc::now().naive_utc().trunc_subsecs(6);
+    assert!(refresh_tokens.rotate(first.id, token("second")).await.unwrap());
+    refresh_tokens.revoke_family(family_id).await.unwrap();
+    let after = Utc::now().naive_utc();
+
+    let used_at = refresh_tokens.find_by_hash("first").await.unwrap().used_at.unwrap();
+    let revoked_at = refresh_tokens.find_by_hash("second").await.unwrap().revoked_at.unwrap();
+    for time in [used_at, revoked_at] {
+        assert!(before <= time && time <= after, "{} not in {}..{}", time, before, after);
+    }
+}
+
+#[tokio::test]
+#[ignore = "needs Postgres, see TEST_DATABASE_URL"]
+async fn status_changes_are_stamped_in_utc_whatever_the_session_time_zone() {
+    let database = TestDatabase::create();
+    set_time_zone(&database.db_pool, "Pacific/Kiritimati");
+    let transaction_provider = PostgresTransactionProvider::new(Arc::clone(&database.db_pool));
+    let mission_viewing = MissionViewingPostgres::new(Arc::clone(&database.db_pool));
+    let chief_id = register(&database.db_pool, "chief").await;
+    let cancelled_id = add_mission(&database.db_pool, chief_id, "Heist").await;
+    let removed_id = add_mission(&database.db_pool, chief_id, "Escape").await;
+
+    let before = Utc::now().naive_utc().trunc_subsecs(6);
+    transaction_provider
+        .transaction(move |transaction| {
+            transaction.change_status(AddMissionStatusHistoryEntity {
+                mission_id: cancelled_id,
+                from_status: MissionStatuses::Open,
+                to_status: MissionStatuses::Cancelled,
+                actor_id: chief_id,
+                reason: None,
+            })?;
+            transaction.remove_mission(removed_id, chief_id)?;
+
+            Ok(())
+        })
+        .await
+        .unwrap();
+    let after = Utc::now().naive_utc();
+
+    let history = mission_viewing.get_status_history(cancelled_id).await.unwrap();
+    let updated_at = mission_viewing.get_one(cancelled_id).await.unwrap().updated_at;
+    assert_eq!(history.len(), 1);
+    assert_eq!(history[0].created_at, updated_at);
+    let deleted_at = missions::table
+        .filter(missions::id.eq(removed_id))
+        .select(missions::deleted_at)
+        .first::<Option<NaiveDateTime>>(&mut database.db_pool.get().unwrap())
+        .unwrap()
+        .unwrap();
+    for time in [updated_at, deleted_at] {
+        assert!(before <= time && time <= after, "{} not in {}..{}", time, before, after);
+    }
+}
+
+#[tokio::test]
+#[ignore = "needs Postgres, see TEST_DATABASE_URL"]
+async fn crew_bounds_are_backfilled_from_the_configured_limit() {
+    let database = TestDatabase::create();
+    revert_migrations_from(&database.db_pool, "20261018120000");
+    let chief_id = register(&database.db_pool, "chief").await;
+    let mut crew_ids = Vec::new();
+    for index in 0..6 {
+        crew_ids.push(register(&database.db_pool, &format!("crew{}", index)).await);
+    }
+    let mut conn = database.db_pool.get().unwrap();
+    let mission_ids = sql_query(
+        "INSERT INTO missions (name, status, chief_id) VALUES ('Heist', 'Open', $1), ('Escape', 'Open', $1) \
+         RETURNING id",
+    )
+    .bind::<Integer, _>(chief_id)
+    .load::<MissionId>(&mut conn)
+    .unwrap();
+    let (crowded_id, quiet_id) = (mission_ids[0].id, mission_ids[1].id);
+    for (index, brawler_id) in crew_ids.iter().enumerate() {
+        let mission_id = if index < 2 { quiet_id } else { crowded_id };
+        sql_query("INSERT INTO crew_memberships (mission_id, brawler_id) VALUES ($1, $2)")
+            .bind::<Integer, _>(mission_id)
+            .bind::<Integer, _>(brawler_id)
+            .execute(&mut conn)
+            .unwrap();
+    }
+    drop(conn);
+
+    run_migrations(&database.db_pool, 3).unwrap();
+
+    let mission_viewing = MissionViewingPostgres::new(Arc::clone(&database.db_pool));
+    let crowded = mission_viewing.get_one(crowded_id).await.unwrap();
+    let quiet = mission_viewing.get_one(quiet_id).await.unwrap();
+    assert_eq!((crowded.min_crew, crowded.max_crew), (1, 4));
+    assert_eq!((quiet.min_crew, quiet.max_crew), (1, 3));
+
+    let error = sql_query("UPDATE missions SET min_crew = 5 WHERE id = $1")
+        .bind::<Integer, _>(quiet_id)
+        .execute(&mut database.db_pool.get().unwrap())
+        .unwrap_err();
+    assert!(error.to_string().contains("check_mission_crew_bounds"), "{}", error);
+}
+
+#[tokio::test]
+#[ignore = "needs Postgres, see TEST_DATABASE_URL"]
+async fn unknown_mission_statuses_stop_the_enum_migration() {
+    let database = TestDatabase::create();
+    revert_migrations_from(&database.db_pool, "20261018140000");
+    let chief_id = register(&database.db_pool, "chief").await;
+    let mission_ids = sql_query(
+        "INSERT INTO missions (name, status, chief_id, max_crew) \
+         VALUES ('Heist', 'in_progress', $1, 3), ('Escape', 'Archived', $1, 3) RETURNING id",
+    )
+    .bind::<Integer, _>(chief_id)
+    .load::<MissionId>(&mut database.db_pool.get().unwrap())
+    .unwrap();
+    let (known_id, unknown_id) = (mission_ids[0].id, mission_ids[1].id);
+
+    let error = run_migrations(&database.db_pool, 3).unwrap_err();
+
+    assert!(error.to_string().contains("'Archived'"), "{}", error);
+    let archived = sql_query("SELECT id FROM missions WHERE status = 'Archived'")
+        .load::<MissionId>(&mut database.db_pool.get().unwrap())
+        .unwrap();
+    assert_eq!(archived.len(), 1);
+
+    sql_query("UPDATE missions SET status = 'Cancelled' WHERE id = $1")
+        .bind::<Integer, _>(unknown_id)
+        .execute(&mut database.db_pool.get().unwrap())
+        .unwrap();
+    run_migrations(&database.db_pool, 3).unwrap();
+
+    let mission_viewing = MissionViewingPostgres::new(Arc::clone(&database.db_pool));
+    assert_eq!(
+        mission_viewing.get_one(known_id).await.unwrap().status,
+        MissionStatuses::InProgress
+    );
+}